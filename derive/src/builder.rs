@@ -0,0 +1,124 @@
+use crate::parsing::RuleAttribute;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+
+/// Generate a builder for a struct with named fields
+///
+/// Its setters check the rules of the field that don't depend on
+/// the other fields and its `build` method returns a `Valid` value.
+///
+/// The setters and `build` all take `&mut self`, so they can be chained
+/// from `builder()` and a rejected value is returned as an error without
+/// losing the builder nor the values already set. `build` checks that the
+/// required fields are set before taking the values out of the builder.
+pub fn expand_builder(input: &syn::DeriveInput) -> TokenStream {
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "The builder is only implemented for structs with named fields",
+            )
+            .to_compile_error()
+        }
+    };
+    let vis = &input.vis;
+    let name = &input.ident;
+    let builder = format_ident!("{}Builder", name);
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents: Vec<&syn::Ident> = fields.iter().flat_map(|field| &field.ident).collect();
+    let types = fields.iter().map(|field| &field.ty);
+    let setters = fields.iter().map(setter);
+    let required_checks = fields.iter().map(required_check);
+    let values = fields.iter().map(field_value);
+
+    quote! {
+        #[doc = concat!("Builder of a valid [`", stringify!(#name), "`]")]
+        #vis struct #builder #impl_generics #where_clause {
+            #( #idents: ::core::option::Option<#types> ),*
+        }
+
+        impl #impl_generics #name #type_generics #where_clause {
+            #vis fn builder() -> #builder #type_generics {
+                #builder {
+                    #( #idents: ::core::option::Option::None ),*
+                }
+            }
+        }
+
+        impl #impl_generics #builder #type_generics #where_clause {
+            #( #setters )*
+
+            #vis fn build(&mut self) -> ::core::result::Result<type_rules::Valid<#name #type_generics>, String> {
+                #( #required_checks )*
+                type_rules::Valid::new(#name {
+                    #( #idents: #values ),*
+                })
+            }
+        }
+    }
+}
+
+fn setter(field: &syn::Field) -> TokenStream {
+    let vis = &field.vis;
+    let ident = &field.ident;
+    let ty = &field.ty;
+    let rules = RuleAttribute::parse_attributes(&field.attrs)
+        .into_iter()
+        .filter(|rule| !uses_self(rule.to_token_stream()));
+    quote! {
+        #vis fn #ident(&mut self, value: #ty) -> ::core::result::Result<&mut Self, String> {
+            #( type_rules::Rule::check(&#rules, &value)?; )*
+            self.#ident = ::core::option::Option::Some(value);
+            ::core::result::Result::Ok(self)
+        }
+    }
+}
+
+/// Error of `build` when a required field is not set, an `Option`
+/// field is `None` by default
+fn required_check(field: &syn::Field) -> TokenStream {
+    if is_option(&field.ty) {
+        return TokenStream::new();
+    }
+    let ident = &field.ident;
+    let message = format!("Missing field `{}`", quote!(#ident));
+    quote! {
+        if self.#ident.is_none() {
+            return ::core::result::Result::Err(::std::string::String::from(#message));
+        }
+    }
+}
+
+/// The value of a field in `build`, taken out of the builder
+fn field_value(field: &syn::Field) -> TokenStream {
+    let ident = &field.ident;
+    match is_option(&field.ty) {
+        true => quote! { self.#ident.take().flatten() },
+        false => quote! { self.#ident.take().unwrap() },
+    }
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => segment.ident == "Option",
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// A rule that uses `self` depends on the other fields and can only
+/// be checked by `build`
+fn uses_self(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "self",
+        TokenTree::Group(group) => uses_self(group.stream()),
+        _ => false,
+    })
+}
@@ -0,0 +1,171 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::Token;
+
+/// Input of the `valid!` macro: `valid!(Type, literal)`
+pub struct ValidLiteral {
+    ty: syn::Path,
+    lit: syn::Expr,
+}
+
+impl Parse for ValidLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let lit = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { ty, lit })
+    }
+}
+
+impl ValidLiteral {
+    pub fn expand(&self) -> TokenStream {
+        let Self { ty, lit } = self;
+        let value = match const_value(lit) {
+            Some(value) => value,
+            None => {
+                return syn::Error::new_spanned(lit, "valid! only accepts a literal value")
+                    .to_compile_error()
+            }
+        };
+        let inner = match lit {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(_),
+                ..
+            }) => quote! { ::core::convert::Into::into(#lit) },
+            _ => quote! { #lit },
+        };
+        quote! {
+            {
+                const _: () = type_rules::__private::check_const_rules(
+                    <#ty as type_rules::__private::ConstRules>::RULES,
+                    &#value,
+                );
+                ::std::sync::LazyLock::new(|| {
+                    type_rules::Valid::new(#ty(#inner)).expect("Invalid literal value")
+                })
+            }
+        }
+    }
+}
+
+/// Generate the list of the rules of a newtype that can be
+/// evaluated at compile time
+pub fn const_rules(rules: &[syn::Expr]) -> TokenStream {
+    let const_rules = rules.iter().flat_map(const_rule);
+    quote! {
+        &[ #( #const_rules ),* ]
+    }
+}
+
+fn const_rule(rule: &syn::Expr) -> Vec<TokenStream> {
+    let call = match rule {
+        syn::Expr::Call(call) => call,
+        _ => return Vec::new(),
+    };
+    let name = match &*call.func {
+        syn::Expr::Path(path) => match path.path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => return Vec::new(),
+        },
+        _ => return Vec::new(),
+    };
+    let args: Vec<&syn::Expr> = call.args.iter().collect();
+    let rules = match (name.as_str(), args.as_slice()) {
+        ("And", [first, second]) => {
+            return const_rule(first)
+                .into_iter()
+                .chain(const_rule(second))
+                .collect()
+        }
+        ("MinLength", [min]) => vec![length_rule("MinLength", min)],
+        ("MaxLength", [max]) => vec![length_rule("MaxLength", max)],
+        ("MinMaxLength", [min, max]) => {
+            vec![length_rule("MinLength", min), length_rule("MaxLength", max)]
+        }
+        ("MinRange", [min]) => vec![range_rule("MinRange", min)],
+        ("MaxRange", [max]) => vec![range_rule("MaxRange", max)],
+        ("MinMaxRange", [min, max]) => {
+            vec![range_rule("MinRange", min), range_rule("MaxRange", max)]
+        }
+        ("In", [syn::Expr::Array(values), message]) if is_str_literal(message) => {
+            let values: Option<Vec<TokenStream>> = values.elems.iter().map(const_value).collect();
+            vec![values.map(|values| {
+                quote! {
+                    type_rules::__private::ConstRule::In(&[ #( #values ),* ], #message)
+                }
+            })]
+        }
+        _ => Vec::new(),
+    };
+    rules.into_iter().flatten().collect()
+}
+
+fn length_rule(name: &str, arg: &syn::Expr) -> Option<TokenStream> {
+    match arg {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => {
+            let value = Literal::usize_unsuffixed(lit.base10_parse().ok()?);
+            let variant = syn::Ident::new(name, Span::call_site());
+            Some(quote! { type_rules::__private::ConstRule::#variant(#value) })
+        }
+        _ => None,
+    }
+}
+
+fn range_rule(name: &str, arg: &syn::Expr) -> Option<TokenStream> {
+    let value = const_value(arg)?;
+    let variant = syn::Ident::new(name, Span::call_site());
+    Some(quote! { type_rules::__private::ConstRule::#variant(#value) })
+}
+
+fn is_str_literal(expr: &syn::Expr) -> bool {
+    matches!(
+        expr,
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(_),
+            ..
+        })
+    )
+}
+
+fn const_value(expr: &syn::Expr) -> Option<TokenStream> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => literal_value(lit, false),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => match &**expr {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => literal_value(lit, true),
+            _ => None,
+        },
+        syn::Expr::Group(group) => const_value(&group.expr),
+        _ => None,
+    }
+}
+
+fn literal_value(lit: &syn::Lit, negative: bool) -> Option<TokenStream> {
+    let sign = if negative { -1 } else { 1 };
+    match lit {
+        syn::Lit::Str(lit) if !negative => {
+            Some(quote! { type_rules::__private::ConstValue::Str(#lit) })
+        }
+        syn::Lit::Int(lit) if matches!(lit.suffix(), "f32" | "f64") => {
+            let value = Literal::f64_unsuffixed(sign as f64 * lit.base10_parse::<f64>().ok()?);
+            Some(quote! { type_rules::__private::ConstValue::Float(#value) })
+        }
+        syn::Lit::Int(lit) => {
+            let value = Literal::i128_unsuffixed(sign * lit.base10_parse::<i128>().ok()?);
+            Some(quote! { type_rules::__private::ConstValue::Int(#value) })
+        }
+        syn::Lit::Float(lit) => {
+            let value = Literal::f64_unsuffixed(sign as f64 * lit.base10_parse::<f64>().ok()?);
+            Some(quote! { type_rules::__private::ConstValue::Float(#value) })
+        }
+        _ => None,
+    }
+}
@@ -1,24 +1,181 @@
 extern crate proc_macro;
 extern crate proc_macro2;
 
-use crate::parsing::from_ast;
+use crate::builder::expand_builder;
+use crate::const_rules::{const_rules, ValidLiteral};
+use crate::parsing::{from_ast, TypeRulesOptions};
+use crate::validate_args::expand_validate_args;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, ItemFn};
 
+mod builder;
+mod const_rules;
 mod parsing;
+#[cfg(feature = "regex")]
+mod regex;
+mod validate_args;
 
-#[proc_macro_derive(Validator, attributes(rule))]
+/// Derive `Validator` with the `#[rule(...)]` attribute on the fields
+///
+/// The `#[type_rules(...)]` attribute of the type takes the `builder` and
+/// `const_rules` options, any other option is an error.
+///
+/// ```compile_fail
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// #[type_rules(const_rule)]
+/// struct Name(#[rule(MinLength(1))] String);
+/// ```
+///
+/// `const_rules` can only be used on a tuple struct with a single field.
+///
+/// ```compile_fail
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// #[type_rules(const_rules)]
+/// struct Name {
+///     #[rule(MinLength(1))]
+///     value: String,
+/// }
+/// ```
+#[proc_macro_derive(Validator, attributes(rule, type_rules))]
 pub fn derive_validator(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     expand_derive_validator(input).into()
 }
 
+/// Derive `Sanitize` with the `#[sanitizer(...)]` attribute on the fields
+///
+/// The attribute can't be named `#[sanitize(...)]`, the compiler reports
+/// it as ambiguous with its own built-in `sanitize` attribute.
+#[proc_macro_derive(Sanitize, attributes(sanitizer))]
+pub fn derive_sanitize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_derive_sanitize(input).into()
+}
+
+/// Create a [`Valid`] value from a literal, the rules of the type that
+/// can be evaluated at compile time such as lengths, ranges and `In` make
+/// the build fail if the literal is invalid
+///
+/// The other rules are checked on the first access of the returned
+/// [`LazyLock`](std::sync::LazyLock), that panics if the value is invalid.
+///
+/// Works with the single field tuple structs that derive `Validator` with
+/// the `#[type_rules(const_rules)]` attribute, that generates the list of
+/// their rules evaluated at compile time.
+///
+/// [`Valid`]: https://docs.rs/type-rules/latest/type_rules/struct.Valid.html
+///
+/// # Example
+///
+/// ```
+/// use std::sync::LazyLock;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// #[type_rules(const_rules)]
+/// struct Username(#[rule(MinMaxLength(3, 30))] String);
+///
+/// static DEFAULT_USERNAME: LazyLock<Valid<Username>> = valid!(Username, "admin");
+///
+/// assert_eq!(DEFAULT_USERNAME.0, "admin");
+/// ```
+///
+/// An invalid literal doesn't compile:
+///
+/// ```compile_fail
+/// use std::sync::LazyLock;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// #[type_rules(const_rules)]
+/// struct Username(#[rule(MinMaxLength(3, 30))] String);
+///
+/// static DEFAULT_USERNAME: LazyLock<Valid<Username>> = valid!(Username, "ad");
+/// ```
+#[proc_macro]
+pub fn valid(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    parse_macro_input!(input as ValidLiteral).expand().into()
+}
+
+/// Check the arguments of a function with the `#[rule(...)]` attribute
+/// and its return value with the `#[ensure(...)]` attribute
+///
+/// If the function returns a `Result`, the error is returned, its
+/// error type must implement `From<String>`. Otherwise, the function
+/// panics in debug builds. The message of the error or of the panic
+/// starts with the argument that is not valid.
+///
+/// The returned value is checked with its type, so `#[ensure(...)]` can't
+/// be used on a function that returns an `impl Trait`.
+///
+/// # Example
+///
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[validate_args]
+/// #[ensure(MinRange(1))]
+/// fn count_words(#[rule(MinLength(1))] text: &str) -> Result<usize, String> {
+///     Ok(text.split_whitespace().count())
+/// }
+///
+/// assert_eq!(count_words("Hello world"), Ok(2));
+/// assert_eq!(
+///     count_words(""),
+///     Err(String::from("Invalid argument `text`: Value is too short"))
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use type_rules::prelude::*;
+///
+/// #[validate_args]
+/// #[ensure(MinLength(1))]
+/// fn words(text: &str) -> impl Iterator<Item = &str> {
+///     text.split_whitespace()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn validate_args(
+    _attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+
+    expand_validate_args(input).into()
+}
+
 fn expand_derive_validator(input: DeriveInput) -> TokenStream {
+    let options = match TypeRulesOptions::from_attributes(&input.attrs) {
+        Ok(options) => options,
+        Err(err) => return err.to_compile_error(),
+    };
+    let builder = match options.builder {
+        true => Some(expand_builder(&input)),
+        false => None,
+    };
+    let data = from_ast(&input.data);
+    let const_rules = match (options.const_rules, data.newtype_rules()) {
+        (false, _) => None,
+        (true, Some(rules)) => Some(expand_const_rules(&input, rules)),
+        (true, None) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "const_rules can only be used on a tuple struct with a single field",
+            )
+            .to_compile_error()
+        }
+    };
     let name = input.ident;
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-    let body = from_ast(&input.data).body(&name);
+    let body = data.body(&name);
 
     quote! {
         impl #impl_generics type_rules::Validator for #name #type_generics #where_clause {
@@ -26,5 +183,34 @@ fn expand_derive_validator(input: DeriveInput) -> TokenStream {
                 #body
             }
         }
+
+        #const_rules
+
+        #builder
+    }
+}
+
+fn expand_const_rules(input: &DeriveInput, rules: &[syn::Expr]) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let rules = const_rules(rules);
+    quote! {
+        impl #impl_generics type_rules::__private::ConstRules for #name #type_generics #where_clause {
+            const RULES: &'static [type_rules::__private::ConstRule] = #rules;
+        }
+    }
+}
+
+fn expand_derive_sanitize(input: DeriveInput) -> TokenStream {
+    let name = input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let body = from_ast(&input.data).sanitize_body(&name);
+
+    quote! {
+        impl #impl_generics type_rules::Sanitize for #name #type_generics #where_clause {
+            fn sanitize(&mut self) {
+                #body
+            }
+        }
     }
 }
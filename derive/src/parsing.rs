@@ -17,6 +17,7 @@ pub struct Struct {
 pub struct Field {
     member: syn::Member,
     rules: Vec<syn::Expr>,
+    sanitizers: Vec<syn::Expr>,
 }
 
 pub enum Style {
@@ -30,7 +31,7 @@ pub struct Variant {
     def: Struct,
 }
 
-struct RuleAttribute {
+pub struct RuleAttribute {
     expr: Punctuated<syn::Expr, Token![,]>,
 }
 
@@ -42,6 +43,37 @@ pub fn from_ast(data: &syn::Data) -> Data {
     }
 }
 
+/// Options of the `#[type_rules(...)]` attributes of a container,
+/// such as `#[type_rules(builder, const_rules)]`
+#[derive(Default)]
+pub struct TypeRulesOptions {
+    pub builder: bool,
+    pub const_rules: bool,
+}
+
+impl TypeRulesOptions {
+    pub fn from_attributes(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("type_rules")) {
+            let idents =
+                attr.parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated)?;
+            for ident in idents {
+                match ident.to_string().as_str() {
+                    "builder" => options.builder = true,
+                    "const_rules" => options.const_rules = true,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            ident,
+                            "unknown type_rules option, expected `builder` or `const_rules`",
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
 fn enum_from_ast(variants: &Punctuated<syn::Variant, Token![,]>) -> Vec<Variant> {
     variants
         .iter()
@@ -79,10 +111,30 @@ fn fields_from_ast(fields: &Punctuated<syn::Field, Token![,]>) -> Vec<Field> {
                 None => syn::Member::Unnamed(i.into()),
             },
             rules: RuleAttribute::parse_attributes(&field.attrs),
+            sanitizers: RuleAttribute::parse_named_attributes(&field.attrs, "sanitizer")
+                .into_iter()
+                .map(sanitizer_from_keyword)
+                .collect(),
         })
         .collect()
 }
 
+/// Replace the keywords of the `sanitizer` attribute such as `trim`
+/// by the matching sanitizer, other expressions are kept as is
+fn sanitizer_from_keyword(expr: syn::Expr) -> syn::Expr {
+    let sanitizer = match &expr {
+        syn::Expr::Path(path) if path.path.is_ident("trim") => quote! { Trim },
+        syn::Expr::Path(path) if path.path.is_ident("lowercase") => quote! { ToLowercase },
+        syn::Expr::Path(path) if path.path.is_ident("uppercase") => quote! { ToUppercase },
+        syn::Expr::Path(path) if path.path.is_ident("collapse_whitespace") => {
+            quote! { CollapseWhitespace }
+        }
+        syn::Expr::Path(path) if path.path.is_ident("nested") => quote! { Nested },
+        _ => return expr,
+    };
+    syn::parse_quote! { type_rules::sanitizers::#sanitizer() }
+}
+
 impl Parse for RuleAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
@@ -92,10 +144,18 @@ impl Parse for RuleAttribute {
 }
 
 impl RuleAttribute {
+    /// Expressions of the `rule` attributes, with their regexes compiled
     pub fn parse_attributes(attrs: &[syn::Attribute]) -> Vec<syn::Expr> {
+        Self::parse_named_attributes(attrs, "rule")
+            .into_iter()
+            .map(Self::prepare_rule)
+            .collect()
+    }
+
+    pub fn parse_named_attributes(attrs: &[syn::Attribute], name: &str) -> Vec<syn::Expr> {
         attrs
             .iter()
-            .filter(|attr| attr.path.is_ident("rule"))
+            .filter(|attr| attr.path.is_ident(name))
             .flat_map(Self::parse_attribute)
             .collect()
     }
@@ -107,6 +167,17 @@ impl RuleAttribute {
             .into_iter()
             .collect()
     }
+
+    #[cfg(feature = "regex")]
+    fn prepare_rule(mut expr: syn::Expr) -> syn::Expr {
+        crate::regex::compile_static_regexes(&mut expr);
+        expr
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn prepare_rule(expr: syn::Expr) -> syn::Expr {
+        expr
+    }
 }
 
 impl Data {
@@ -117,6 +188,38 @@ impl Data {
         }
     }
 
+    pub fn sanitize_body(&self, ident: &syn::Ident) -> TokenStream {
+        match self {
+            Data::Enum(variants) => {
+                let variants_arms = variants
+                    .iter()
+                    .map(|variant| variant.sanitize_match_arm(ident));
+                quote! {
+                    match self {
+                        #( #variants_arms ),*
+                    }
+                }
+            }
+            Data::Struct(data) => {
+                let fields_sanitizers = data.fields.iter().map(Field::sanitizers);
+                quote! {
+                    #( #fields_sanitizers )*
+                }
+            }
+        }
+    }
+
+    /// Rules of the field of a single field tuple struct
+    pub fn newtype_rules(&self) -> Option<&[syn::Expr]> {
+        match self {
+            Data::Struct(Struct {
+                fields,
+                style: Style::Tuple,
+            }) if fields.len() == 1 => Some(&fields[0].rules),
+            _ => None,
+        }
+    }
+
     fn enum_body(variants: &[Variant], ident: &syn::Ident) -> TokenStream {
         let variants_arms = variants.iter().map(|variant| variant.match_arm(ident));
         quote! {
@@ -147,6 +250,16 @@ impl Variant {
         }
     }
 
+    fn sanitize_match_arm(&self, data_ident: &syn::Ident) -> TokenStream {
+        let case = self.match_arm_case(data_ident);
+        let fields_sanitizers = self.def.fields.iter().map(Field::sanitizers_named);
+        quote! {
+            #case => {
+                #( #fields_sanitizers )*
+            }
+        }
+    }
+
     fn match_arm_case(&self, data_ident: &syn::Ident) -> TokenStream {
         let ident = &self.ident;
         let fields_names = self.def.fields.iter().map(Field::get_named_ident);
@@ -166,12 +279,29 @@ impl Variant {
 
 impl Field {
     fn rules(&self) -> TokenStream {
-        let Self { rules, member } = &self;
+        let Self { rules, member, .. } = &self;
         quote! {
             #( type_rules::Rule::check(&#rules, &self.#member)?; )*
         }
     }
 
+    fn sanitizers(&self) -> TokenStream {
+        let Self {
+            sanitizers, member, ..
+        } = &self;
+        quote! {
+            #( type_rules::Sanitizer::sanitize(&#sanitizers, &mut self.#member); )*
+        }
+    }
+
+    fn sanitizers_named(&self) -> TokenStream {
+        let ident = self.get_named_ident();
+        let sanitizers = &self.sanitizers;
+        quote! {
+            #( type_rules::Sanitizer::sanitize(&#sanitizers, #ident); )*
+        }
+    }
+
     fn rules_named(&self) -> TokenStream {
         let ident = self.get_named_ident();
         let rules = &self.rules;
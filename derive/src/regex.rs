@@ -0,0 +1,74 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::visit_mut::{self, VisitMut};
+
+/// Rewrite every `RegEx("literal")` found in a rule expression
+///
+/// Only the `RegEx` and `type_rules::rules::RegEx` paths are rewritten,
+/// so a `RegEx` of another module written with its path is kept as is.
+///
+/// The pattern is compiled during the macro expansion so an invalid
+/// regex is reported as a compile error, and the call is replaced by
+/// a `static` regex that is compiled only once at the first check.
+pub fn compile_static_regexes(expr: &mut syn::Expr) {
+    StaticRegexes.visit_expr_mut(expr);
+}
+
+struct StaticRegexes;
+
+impl VisitMut for StaticRegexes {
+    fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+        match regex_literal(expr) {
+            Some(lit) => *expr = syn::Expr::Verbatim(static_regex(&lit)),
+            None => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+}
+
+fn regex_literal(expr: &syn::Expr) -> Option<syn::LitStr> {
+    let call = match expr {
+        syn::Expr::Call(call) if call.args.len() == 1 => call,
+        _ => return None,
+    };
+    match &*call.func {
+        syn::Expr::Path(path) if is_regex_path(&path.path) => {}
+        _ => return None,
+    }
+    match call.args.first()? {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Some(lit.clone()),
+        _ => None,
+    }
+}
+
+fn is_regex_path(path: &syn::Path) -> bool {
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    match path.leading_colon {
+        Some(_) => segments == ["type_rules", "rules", "RegEx"],
+        None => segments == ["RegEx"] || segments == ["type_rules", "rules", "RegEx"],
+    }
+}
+
+fn static_regex(lit: &syn::LitStr) -> TokenStream {
+    if let Err(err) = regex::Regex::new(&lit.value()) {
+        let message = format!("Invalid regex: {}", err);
+        return quote_spanned! {lit.span()=>
+            compile_error!(#message)
+        };
+    }
+    quote! {
+        {
+            static REGEX: ::std::sync::OnceLock<type_rules::__private::Regex> =
+                ::std::sync::OnceLock::new();
+            type_rules::__private::StaticRegEx(
+                REGEX.get_or_init(|| type_rules::__private::Regex::new(#lit).unwrap())
+            )
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::parsing::RuleAttribute;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// Add the checks of the `#[rule(...)]` of the arguments at the
+/// beginning of a function and the checks of the `#[ensure(...)]`
+/// on its return value
+pub fn expand_validate_args(mut func: syn::ItemFn) -> TokenStream {
+    let failure = Failure::from_output(&func.sig.output);
+
+    let mut arguments_checks = Vec::new();
+    for input in func.sig.inputs.iter_mut() {
+        let arg = match input {
+            syn::FnArg::Typed(arg) => arg,
+            syn::FnArg::Receiver(_) => continue,
+        };
+        let rules = RuleAttribute::parse_attributes(&arg.attrs);
+        arg.attrs.retain(|attr| !attr.path.is_ident("rule"));
+        if rules.is_empty() {
+            continue;
+        }
+        let ident = match &*arg.pat {
+            syn::Pat::Ident(pat) => pat.ident.clone(),
+            pat => {
+                return syn::Error::new_spanned(pat, "rules can only be used on named arguments")
+                    .to_compile_error()
+            }
+        };
+        let message = format!("Invalid argument `{}`", ident);
+        arguments_checks.extend(
+            rules
+                .iter()
+                .map(|rule| failure.check(rule, &quote! { &#ident }, &message)),
+        );
+    }
+
+    let ensures = RuleAttribute::parse_named_attributes(&func.attrs, "ensure");
+    func.attrs.retain(|attr| !attr.path.is_ident("ensure"));
+
+    let block = &func.block;
+    let body = if ensures.is_empty() {
+        let stmts = &block.stmts;
+        quote! {
+            #( #arguments_checks )*
+            #( #stmts )*
+        }
+    } else {
+        let output = match &func.sig.output {
+            syn::ReturnType::Type(_, ty) if has_impl_trait(ty.to_token_stream()) => {
+                return syn::Error::new_spanned(
+                    ty,
+                    "ensure can't be used on a function that returns an `impl Trait`",
+                )
+                .to_compile_error();
+            }
+            syn::ReturnType::Type(_, ty) => ty.to_token_stream(),
+            syn::ReturnType::Default => quote! { () },
+        };
+        let result = match func.sig.asyncness {
+            Some(_) => quote! { async move #block.await },
+            None => quote! { (move || -> #output #block)() },
+        };
+        let ensures_checks = ensures
+            .iter()
+            .map(|rule| failure.check(rule, &quote! { __value }, "Invalid return value"));
+        let ensures_checks = failure.on_value(quote! { #( #ensures_checks )* });
+        quote! {
+            #( #arguments_checks )*
+            let __result: #output = #result;
+            #ensures_checks
+            __result
+        }
+    };
+
+    let syn::ItemFn {
+        attrs, vis, sig, ..
+    } = &func;
+    quote! {
+        #( #attrs )*
+        #vis #sig {
+            #body
+        }
+    }
+}
+
+/// What to do when a rule is not respected
+enum Failure {
+    /// The function returns a `Result`, the error is returned with
+    /// the same message as the panic
+    ReturnErr,
+    /// The function panics in debug builds
+    DebugPanic,
+}
+
+impl Failure {
+    fn from_output(output: &syn::ReturnType) -> Self {
+        match output {
+            syn::ReturnType::Type(_, ty) => match &**ty {
+                syn::Type::Path(path) if is_result(path) => Failure::ReturnErr,
+                _ => Failure::DebugPanic,
+            },
+            syn::ReturnType::Default => Failure::DebugPanic,
+        }
+    }
+
+    fn check(&self, rule: &syn::Expr, value: &TokenStream, message: &str) -> TokenStream {
+        match self {
+            Failure::ReturnErr => quote! {
+                if let Err(err) = type_rules::Rule::check(&#rule, #value) {
+                    return Err(::core::convert::From::from(::std::format!("{}: {}", #message, err)));
+                }
+            },
+            Failure::DebugPanic => quote! {
+                if cfg!(debug_assertions) {
+                    if let Err(err) = type_rules::Rule::check(&#rule, #value) {
+                        panic!("{}: {}", #message, err);
+                    }
+                }
+            },
+        }
+    }
+
+    /// Bind `__value` to the returned value, or to the value
+    /// inside the `Ok` of a `Result`
+    fn on_value(&self, checks: TokenStream) -> TokenStream {
+        match self {
+            Failure::ReturnErr => quote! {
+                if let Ok(__value) = &__result {
+                    #checks
+                }
+            },
+            Failure::DebugPanic => quote! {
+                let __value = &__result;
+                #checks
+            },
+        }
+    }
+}
+
+/// The returned value is bound with its type, that can't be named if
+/// it is or contains an `impl Trait`
+fn has_impl_trait(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => ident == "impl",
+        proc_macro2::TokenTree::Group(group) => has_impl_trait(group.stream()),
+        _ => false,
+    })
+}
+
+fn is_result(path: &syn::TypePath) -> bool {
+    match path.path.segments.last() {
+        Some(segment) => segment.ident == "Result",
+        None => false,
+    }
+}
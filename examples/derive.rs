@@ -32,7 +32,7 @@ struct SignUpForm {
 }
 
 #[derive(Validator)]
-struct PastDate(#[rule(MaxRange(Utc::now()))] DateTime<Utc>);
+struct PastDate(#[rule(MaxRange(now()))] DateTime<Utc>);
 
 #[derive(Validator)]
 struct FloatWrapper(#[rule(MinMaxRange(0_f32, 100_f32))] f32);
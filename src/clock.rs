@@ -0,0 +1,213 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+
+static DEFAULT_CLOCK: RwLock<Option<Arc<dyn Clock + Send + Sync>>> = RwLock::new(None);
+
+thread_local! {
+    static THREAD_CLOCK: RefCell<Option<Rc<dyn Clock>>> = RefCell::new(None);
+}
+
+/// Define the source of the current time of the rules that depend on it
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use type_rules::clock::{Clock, FixedClock};
+///
+/// let clock = FixedClock(SystemTime::UNIX_EPOCH + Duration::from_secs(3600));
+/// assert_eq!(clock.now(), SystemTime::UNIX_EPOCH + Duration::from_secs(3600));
+/// ```
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+}
+
+/// Clock of the system, used when no other clock is set
+pub struct SystemClock();
+
+/// Clock stopped at a time, to make the checks deterministic in tests
+pub struct FixedClock(pub SystemTime);
+
+/// Clock used by [`now`] and by the rules that depend on the current time
+///
+/// It is the clock of [`with_clock`] on the current thread, otherwise
+/// the clock of [`set_default_clock`], otherwise the [`SystemClock`]
+pub struct CurrentClock();
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+impl Clock for CurrentClock {
+    fn now(&self) -> SystemTime {
+        if let Some(clock) = THREAD_CLOCK.with(|cell| cell.borrow().clone()) {
+            return clock.now();
+        }
+        let default_clock = DEFAULT_CLOCK
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        match default_clock {
+            Some(clock) => clock.now(),
+            None => SystemClock().now(),
+        }
+    }
+}
+
+impl<F: Fn() -> SystemTime> Clock for F {
+    fn now(&self) -> SystemTime {
+        self()
+    }
+}
+
+/// Current time of the [`CurrentClock`], in any type that can be
+/// created from a [`SystemTime`] such as `DateTime<Utc>` of `chrono`
+/// or `OffsetDateTime` of `time`
+///
+/// Use it instead of `Utc::now()` in the rules so the checks
+/// can be made at another time with [`with_clock`]
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use type_rules::clock::with_clock;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct BirthDate(#[rule(MaxRange(now()))] DateTime<Utc>);
+///
+/// let birth_date = BirthDate(Utc.ymd(2022, 6, 1).and_hms(0, 0, 0));
+/// assert!(birth_date.check_validity().is_ok());
+///
+/// let clock = FixedClock(Utc.ymd(2000, 1, 1).and_hms(0, 0, 0).into());
+/// assert!(with_clock(clock, || birth_date.check_validity()).is_err());
+/// ```
+pub fn now<T: From<SystemTime>>() -> T {
+    T::from(CurrentClock().now())
+}
+
+/// Use a clock on the current thread while running a closure
+///
+/// The previous clock of the thread is restored afterward, even
+/// if the closure panics, so the calls can be nested
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use type_rules::clock::{now, with_clock, FixedClock};
+///
+/// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(3600);
+///
+/// assert_eq!(with_clock(FixedClock(time), now::<SystemTime>), time);
+/// assert_ne!(now::<SystemTime>(), time);
+/// ```
+pub fn with_clock<C: Clock + 'static, R>(clock: C, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Rc<dyn Clock>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            THREAD_CLOCK.with(|cell| *cell.borrow_mut() = previous);
+        }
+    }
+
+    let previous = THREAD_CLOCK.with(|cell| cell.borrow_mut().replace(Rc::new(clock)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Use a clock on all the threads that don't have a clock of [`with_clock`]
+pub fn set_default_clock<C: Clock + Send + Sync + 'static>(clock: C) {
+    *DEFAULT_CLOCK
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(clock));
+}
+
+/// Go back to the [`SystemClock`] on all the threads that don't
+/// have a clock of [`with_clock`]
+pub fn reset_default_clock() {
+    *DEFAULT_CLOCK
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::clock::{now, reset_default_clock, set_default_clock, with_clock, FixedClock};
+    use std::panic::catch_unwind;
+    use std::sync::{Mutex, MutexGuard};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    /// Held by the tests that set the default clock or that read the time
+    /// without [`with_clock`], so they don't run at the same time
+    static DEFAULT_CLOCK_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Lock the default clock and reset it when dropped,
+    /// even if the test panics
+    struct DefaultClockGuard(#[allow(dead_code)] MutexGuard<'static, ()>);
+
+    impl DefaultClockGuard {
+        fn lock() -> Self {
+            DefaultClockGuard(
+                DEFAULT_CLOCK_LOCK
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()),
+            )
+        }
+    }
+
+    impl Drop for DefaultClockGuard {
+        fn drop(&mut self) {
+            reset_default_clock();
+        }
+    }
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    fn is_system_time(time: SystemTime) -> bool {
+        let system_time = SystemTime::now();
+        time <= system_time && time + Duration::from_secs(60) > system_time
+    }
+
+    #[test]
+    fn with_clock_nested() {
+        let _guard = DefaultClockGuard::lock();
+        let times = with_clock(FixedClock(at(1)), || {
+            let inner = with_clock(FixedClock(at(2)), now::<SystemTime>);
+            (now::<SystemTime>(), inner)
+        });
+        assert_eq!(times, (at(1), at(2)));
+        assert!(is_system_time(now()));
+    }
+    #[test]
+    fn with_clock_restored_after_panic() {
+        let _guard = DefaultClockGuard::lock();
+        let result = catch_unwind(|| with_clock(FixedClock(at(1)), || panic!("check failed")));
+        assert!(result.is_err());
+        assert!(is_system_time(now()));
+    }
+    #[test]
+    fn default_clock() {
+        let _guard = DefaultClockGuard::lock();
+        set_default_clock(|| at(3));
+        let times =
+            std::thread::spawn(|| (now::<SystemTime>(), with_clock(FixedClock(at(4)), now)))
+                .join()
+                .unwrap();
+        assert_eq!(times, (at(3), at(4)));
+        reset_default_clock();
+        assert!(is_system_time(now()));
+    }
+}
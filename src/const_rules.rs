@@ -0,0 +1,166 @@
+/// Rules of a type that can be evaluated at compile time
+/// by the `valid!` macro
+///
+/// Implemented by the derive macro for the single field tuple structs
+/// with the `#[type_rules(const_rules)]` attribute.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used with `valid!`",
+    note = "derive `Validator` with the `#[type_rules(const_rules)]` attribute on `{Self}`"
+)]
+pub trait ConstRules {
+    const RULES: &'static [ConstRule];
+}
+
+/// Literal value known at compile time
+pub enum ConstValue {
+    Str(&'static str),
+    Int(i128),
+    Float(f64),
+}
+
+/// A rule with literal parameters
+pub enum ConstRule {
+    MinLength(usize),
+    MaxLength(usize),
+    MinRange(ConstValue),
+    MaxRange(ConstValue),
+    In(&'static [ConstValue], &'static str),
+}
+
+/// Panic, and so fail the constant evaluation, if `value` doesn't
+/// respect one of the `rules`
+///
+/// A rule that doesn't apply to the kind of the value is ignored,
+/// it will be checked at runtime.
+pub const fn check_const_rules(rules: &[ConstRule], value: &ConstValue) {
+    let mut i = 0;
+    while i < rules.len() {
+        if let Err(message) = check_const_rule(&rules[i], value) {
+            panic!("{}", message);
+        }
+        i += 1;
+    }
+}
+
+const fn check_const_rule(rule: &ConstRule, value: &ConstValue) -> Result<(), &'static str> {
+    match (rule, value) {
+        (ConstRule::MinLength(min), ConstValue::Str(value)) if value.len() < *min => {
+            Err("Value is too short")
+        }
+        (ConstRule::MaxLength(max), ConstValue::Str(value)) if value.len() > *max => {
+            Err("Value is too long")
+        }
+        (ConstRule::MinRange(min), value) if compare(value, min) == -1 => Err("Value is too low"),
+        (ConstRule::MaxRange(max), value) if compare(value, max) == 1 => Err("Value is too high"),
+        (ConstRule::In(values, message), value) => {
+            let mut i = 0;
+            while i < values.len() {
+                if compare(value, &values[i]) == 0 {
+                    return Ok(());
+                }
+                i += 1;
+            }
+            match comparable(value, values) {
+                true => Err(*message),
+                false => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Return -1, 0 or 1 as [`Ord::cmp`], or 2 when the values
+/// can't be compared
+const fn compare(a: &ConstValue, b: &ConstValue) -> i8 {
+    match (a, b) {
+        (ConstValue::Int(a), ConstValue::Int(b)) => cmp_order(*a < *b, *a > *b),
+        (ConstValue::Float(a), ConstValue::Float(b)) if !a.is_nan() && !b.is_nan() => {
+            cmp_order(*a < *b, *a > *b)
+        }
+        (ConstValue::Str(a), ConstValue::Str(b)) => compare_str(a, b),
+        _ => 2,
+    }
+}
+
+const fn cmp_order(less: bool, greater: bool) -> i8 {
+    match (less, greater) {
+        (true, _) => -1,
+        (_, true) => 1,
+        _ => 0,
+    }
+}
+
+const fn compare_str(a: &str, b: &str) -> i8 {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return cmp_order(a[i] < b[i], a[i] > b[i]);
+        }
+        i += 1;
+    }
+    cmp_order(a.len() < b.len(), a.len() > b.len())
+}
+
+/// An `In` rule only applies if all its values have the kind of the value
+const fn comparable(value: &ConstValue, values: &[ConstValue]) -> bool {
+    let mut i = 0;
+    while i < values.len() {
+        if compare(value, &values[i]) == 2 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_const_rule, ConstRule, ConstValue};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn const_length_ok() {
+        assert_ok!(check_const_rule(
+            &ConstRule::MaxLength(5),
+            &ConstValue::Str("admin")
+        ));
+    }
+    #[test]
+    fn const_length_err() {
+        assert_err!(check_const_rule(
+            &ConstRule::MinLength(6),
+            &ConstValue::Str("admin")
+        ));
+    }
+    #[test]
+    fn const_range_err() {
+        assert_err!(check_const_rule(
+            &ConstRule::MinRange(ConstValue::Float(1.5)),
+            &ConstValue::Float(-2.0)
+        ));
+    }
+    #[test]
+    fn const_range_other_kind_ignored() {
+        assert_ok!(check_const_rule(
+            &ConstRule::MinRange(ConstValue::Float(1.5)),
+            &ConstValue::Int(0)
+        ));
+    }
+    #[test]
+    fn const_in_ok() {
+        const VALUES: &[ConstValue] = &[ConstValue::Str("apple"), ConstValue::Str("pear")];
+        assert_ok!(check_const_rule(
+            &ConstRule::In(VALUES, "Value need to be a fruit"),
+            &ConstValue::Str("pear")
+        ));
+    }
+    #[test]
+    fn const_in_err() {
+        const VALUES: &[ConstValue] = &[ConstValue::Int(1), ConstValue::Int(2)];
+        assert_err!(check_const_rule(
+            &ConstRule::In(VALUES, "Value need to be 1 or 2"),
+            &ConstValue::Int(3)
+        ));
+    }
+}
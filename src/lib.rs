@@ -1,9 +1,21 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 
+// Allow the code generated by the derive macro to be used in this crate
+extern crate self as type_rules;
+
 /// A module that contains all the rules
 pub mod rules;
 
+/// A module that contains all the sanitizers
+pub mod sanitizers;
+
+/// A module that contains the clocks of the rules that depend on the current time
+pub mod clock;
+
+mod const_rules;
+#[cfg(feature = "derive")]
+mod rule_type;
 mod valid;
 
 /// A convenience module appropriate for glob imports `use type_rules::prelude::*;`
@@ -13,9 +25,29 @@ pub mod prelude;
 #[doc(hidden)]
 pub use type_rules_derive::*;
 
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+#[doc(inline)]
+pub use type_rules_derive::{valid, validate_args};
+
 #[doc(inline)]
 pub use rules::Rule;
 
+#[doc(inline)]
+pub use sanitizers::Sanitizer;
+
+/// Items used by the code generated by the derive macro
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::const_rules::*;
+    #[cfg(feature = "regex")]
+    pub use crate::rules::StaticRegEx;
+    #[cfg(feature = "regex")]
+    pub use regex::Regex;
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
 #[doc(inline)]
 pub use valid::Valid;
 
@@ -45,3 +77,44 @@ pub use valid::Valid;
 pub trait Validator {
     fn check_validity(&self) -> Result<(), String>;
 }
+
+/// Normalize a type before its checking
+///
+/// By implementing `Sanitize` for a type, you define the
+/// sanitizers that transform its value
+///
+/// Can be derived with the `derive` feature, the sanitizers of a field
+/// are given with the `#[sanitizer(...)]` attribute. It is not named
+/// `#[sanitize(...)]` because this name is taken by a built-in attribute
+/// of the compiler, that rejects it as ambiguous.
+///
+/// # Example
+///
+/// Basic usage:
+///
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator, Sanitize)]
+/// struct Email(
+///     #[sanitizer(trim, lowercase)]
+///     #[rule(MaxLength(20))]
+///     String
+/// );
+///
+/// let mut email = Email(String::from("  Foo@Example.COM "));
+/// email.sanitize_and_validate().unwrap();
+/// assert_eq!(email.0, "foo@example.com");
+/// ```
+pub trait Sanitize {
+    fn sanitize(&mut self);
+
+    /// Sanitize the value then check its validity
+    fn sanitize_and_validate(&mut self) -> Result<(), String>
+    where
+        Self: Validator,
+    {
+        self.sanitize();
+        self.check_validity()
+    }
+}
@@ -1,2 +1,7 @@
+pub use super::clock::{now, FixedClock};
 pub use super::rules::*;
-pub use super::{Rule, Valid, Validator};
+pub use super::sanitizers::*;
+pub use super::{Rule, Sanitize, Sanitizer, Valid, Validator};
+
+#[cfg(feature = "derive")]
+pub use super::{rule_type, valid, validate_args};
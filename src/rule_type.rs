@@ -0,0 +1,182 @@
+/// Define a newtype that can only contain a valid value
+///
+/// The newtype derives [`Validator`](crate::Validator) with the given rules
+/// and its field is private, it can only be built with `new` or [`TryFrom`]
+/// that check its validity.
+///
+/// It implements [`Deref`](std::ops::Deref), [`AsRef`], [`Display`](std::fmt::Display)
+/// if the inner type implements it and the conversion to the inner type.
+///
+/// With the `serde` feature, it is serialized as the inner type and
+/// its validity is checked when deserialized.
+///
+/// You need the `derive` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// rule_type!(
+///     /// A username in lowercase
+///     #[derive(Debug, Clone)]
+///     pub Username: String = MinMaxLength(3, 30), RegEx(r"^[a-z0-9_]+$")
+/// );
+///
+/// let username = Username::new(String::from("example")).unwrap();
+/// assert_eq!(username.len(), 7);
+/// assert_eq!(username.to_string(), "example");
+///
+/// assert!(Username::try_from(String::from("Example")).is_err());
+/// ```
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+macro_rules! rule_type {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident : $ty:ty = $($rules:tt)+
+    ) => {
+        $(#[$meta])*
+        #[derive($crate::Validator)]
+        $vis struct $name(#[rule($($rules)+)] $ty);
+
+        impl $name {
+            #[allow(dead_code)]
+            pub fn new(value: $ty) -> ::core::result::Result<Self, ::std::string::String> {
+                let value = $name(value);
+                $crate::Validator::check_validity(&value)?;
+                ::core::result::Result::Ok(value)
+            }
+
+            #[allow(dead_code)]
+            pub fn into_inner(self) -> $ty {
+                self.0
+            }
+        }
+
+        impl ::core::ops::Deref for $name {
+            type Target = $ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::AsRef<$ty> for $name {
+            fn as_ref(&self) -> &$ty {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::TryFrom<$ty> for $name {
+            type Error = ::std::string::String;
+
+            fn try_from(value: $ty) -> ::core::result::Result<Self, Self::Error> {
+                $name::new(value)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $ty {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::fmt::Display for $name
+        where
+            for<'a> $ty: ::core::fmt::Display,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        $crate::__rule_type_serde!($name, $ty);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rule_type_serde {
+    ($name:ident, $ty:ty) => {
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name
+        where
+            $ty: $crate::__private::serde::Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let value =
+                    <$ty as $crate::__private::serde::Deserialize>::deserialize(deserializer)?;
+                $name::new(value).map_err($crate::__private::serde::de::Error::custom)
+            }
+        }
+
+        impl $crate::__private::serde::Serialize for $name
+        where
+            for<'a> $ty: $crate::__private::serde::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rule_type_serde {
+    ($name:ident, $ty:ty) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use claim::{assert_err, assert_ok};
+
+    rule_type!(#[derive(Debug)] Percent: u8 = MaxRange(100));
+
+    #[test]
+    fn rule_type_ok() {
+        assert_ok!(Percent::new(100));
+    }
+    #[test]
+    fn rule_type_err() {
+        assert_err!(Percent::try_from(101));
+    }
+    #[test]
+    fn rule_type_into_inner() {
+        let percent = Percent::new(50).unwrap();
+        assert_eq!(u8::from(percent), 50);
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_tests {
+        use crate::prelude::*;
+        use claim::{assert_err, assert_ok};
+
+        rule_type!(#[derive(Debug)] Username: String = MinMaxLength(3, 30));
+
+        #[test]
+        fn rule_type_serde_ok() {
+            let res: serde_json::Result<Username> = serde_json::from_str("\"example\"");
+            assert_ok!(res);
+        }
+        #[test]
+        fn rule_type_serde_err() {
+            let res: serde_json::Result<Username> = serde_json::from_str("\"ex\"");
+            assert_err!(res);
+        }
+        #[test]
+        fn rule_type_serde_serialize() {
+            let username = Username::new(String::from("example")).unwrap();
+            let serialized = serde_json::to_string(&username).unwrap();
+            assert_eq!(&serialized, "\"example\"")
+        }
+    }
+}
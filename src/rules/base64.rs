@@ -0,0 +1,128 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be encoded in Base64
+///
+/// The standard alphabet is used by default and the padding is
+/// required, with the URL-safe alphabet the padding is optional.
+/// The length of the decoded data can be limited, which
+/// [`MaxLength`](super::MaxLength) can't do on the encoded text.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Avatar(#[rule(Base64::new().max_decoded_length(1024))] String);
+///
+/// #[derive(Validator)]
+/// struct Token(#[rule(Base64::new().url_safe())] String);
+///
+/// assert!(Avatar(String::from("aGVsbG8gd29ybGQ=")).check_validity().is_ok());
+/// assert!(Avatar(String::from("aGVsbG8gd29ybGQ")).check_validity().is_err());
+/// assert!(Token(String::from("aGVsbG8_d29ybGQ")).check_validity().is_ok());
+/// ```
+pub struct Base64 {
+    url_safe: bool,
+    max_decoded_length: Option<usize>,
+}
+
+impl Base64 {
+    /// Accept the standard alphabet with padding
+    pub const fn new() -> Self {
+        Base64 {
+            url_safe: false,
+            max_decoded_length: None,
+        }
+    }
+
+    /// Accept the URL-safe alphabet, with `-` and `_` instead
+    /// of `+` and `/`, with or without padding
+    pub const fn url_safe(self) -> Self {
+        Base64 {
+            url_safe: true,
+            ..self
+        }
+    }
+
+    /// Only accept the values with at most `max_decoded_length` decoded bytes
+    pub const fn max_decoded_length(self, max_decoded_length: usize) -> Self {
+        Base64 {
+            max_decoded_length: Some(max_decoded_length),
+            ..self
+        }
+    }
+}
+
+impl Default for Base64 {
+    fn default() -> Self {
+        Base64::new()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Base64 {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let data = value.trim_end_matches('=');
+        let padding = value.len() - data.len();
+        let (symbols, padding_required): (&[u8], bool) = match self.url_safe {
+            true => (b"-_", false),
+            false => (b"+/", true),
+        };
+        let valid = data
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || symbols.contains(&c))
+            && data.len() % 4 != 1
+            && match padding {
+                0 => !padding_required || data.len() % 4 == 0,
+                _ => padding <= 2 && value.len() % 4 == 0,
+            };
+        if !valid {
+            return Err(String::from("The value is not valid Base64"));
+        }
+        match self.max_decoded_length {
+            Some(max) if data.len() * 3 / 4 > max => {
+                Err(String::from("The decoded value is too long"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Base64, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn base64_ok() {
+        assert_ok!(Base64::new().check(""));
+        assert_ok!(Base64::new().check("YQ=="));
+        assert_ok!(Base64::new().check("YWI="));
+        assert_ok!(Base64::new().check("+/+/"));
+    }
+    #[test]
+    fn base64_err() {
+        let res_error_message = Base64::new().check("YQ").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The value is not valid Base64");
+        assert_err!(Base64::new().check("YQ==="));
+        assert_err!(Base64::new().check("Y==="));
+        assert_err!(Base64::new().check("-_-_"));
+        assert_err!(Base64::new().check("YQ==YQ=="));
+    }
+    #[test]
+    fn base64_url_safe() {
+        assert_ok!(Base64::new().url_safe().check("-_-_"));
+        assert_ok!(Base64::new().url_safe().check("YQ"));
+        assert_ok!(Base64::new().url_safe().check("YQ=="));
+        assert_err!(Base64::new().url_safe().check("Y"));
+        assert_err!(Base64::new().url_safe().check("+/+/"));
+    }
+    #[test]
+    fn base64_max_decoded_length() {
+        let rule = Base64::new().max_decoded_length(2);
+        assert_ok!(rule.check("YWI="));
+        let res_error_message = rule.check("YWJj").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The decoded value is too long");
+    }
+}
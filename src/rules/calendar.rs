@@ -0,0 +1,257 @@
+use super::Rule;
+use std::cmp::Ordering;
+
+/// Define a date, or a date and time, of `chrono` or `time`
+/// that has a day of the week
+pub trait DayOfWeek {
+    type Weekday: PartialEq;
+
+    fn day_of_week(&self) -> Self::Weekday;
+}
+
+/// Define a time, or a date and time, of `chrono` or `time`
+/// that has a time of the day
+pub trait TimeOfDay {
+    /// Hour and minute of the value, in its own offset
+    fn hour_minute(&self) -> (u32, u32);
+}
+
+/// Rule to constraint a date or a date and time of `chrono` or `time`
+/// to be on one of the given days of the week
+///
+/// The days are of the `Weekday` type of the crate of the value
+///
+/// You need the `chrono` or the `time` feature to use it
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Delivery(
+///     #[rule(Weekdays(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]))]
+///     NaiveDate,
+/// );
+///
+/// assert!(Delivery(NaiveDate::from_ymd(2022, 6, 1)).check_validity().is_ok());
+/// assert!(Delivery(NaiveDate::from_ymd(2022, 6, 4)).check_validity().is_err());
+/// ```
+pub struct Weekdays<'a, W>(pub &'a [W]);
+
+/// Rule to constraint a time or a date and time of `chrono` or `time`
+/// to be in a window of the day, given as `(hour, minute)`
+///
+/// The start is inclusive and the end exclusive, a window whose start is
+/// after its end wraps over midnight, as `TimeWindow((22, 0), (6, 0))`,
+/// and a window whose start is its end is the whole day. The hours must
+/// be lower than 24 and the minutes lower than 60, otherwise every check
+/// returns an error
+///
+/// You need the `chrono` or the `time` feature to use it
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Meeting(#[rule(TimeWindow((9, 0), (17, 30)))] NaiveTime);
+///
+/// assert!(Meeting(NaiveTime::from_hms(17, 29, 59)).check_validity().is_ok());
+/// assert!(Meeting(NaiveTime::from_hms(17, 30, 0)).check_validity().is_err());
+/// ```
+pub struct TimeWindow(pub (u32, u32), pub (u32, u32));
+
+impl<'a, T: DayOfWeek + ?Sized> Rule<T> for Weekdays<'a, T::Weekday> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match self.0.contains(&value.day_of_week()) {
+            true => Ok(()),
+            false => Err(String::from("The day of the week is not allowed")),
+        }
+    }
+}
+
+impl<T: TimeOfDay + ?Sized> Rule<T> for TimeWindow {
+    fn check(&self, value: &T) -> Result<(), String> {
+        for (hour, minute) in [self.0, self.1] {
+            if hour >= 24 || minute >= 60 {
+                return Err(format!("The time {}:{:02} is not valid", hour, minute));
+            }
+        }
+        let time = value.hour_minute();
+        let is_inside = match self.0.cmp(&self.1) {
+            Ordering::Less => self.0 <= time && time < self.1,
+            Ordering::Equal => true,
+            Ordering::Greater => self.0 <= time || time < self.1,
+        };
+        match is_inside {
+            true => Ok(()),
+            false => Err(String::from("The time is outside of the window")),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::{DayOfWeek, TimeOfDay};
+    use chrono::{Datelike, Timelike};
+
+    impl<Tz: chrono::TimeZone> DayOfWeek for chrono::DateTime<Tz> {
+        type Weekday = chrono::Weekday;
+
+        fn day_of_week(&self) -> chrono::Weekday {
+            self.weekday()
+        }
+    }
+
+    impl DayOfWeek for chrono::NaiveDateTime {
+        type Weekday = chrono::Weekday;
+
+        fn day_of_week(&self) -> chrono::Weekday {
+            self.weekday()
+        }
+    }
+
+    impl DayOfWeek for chrono::NaiveDate {
+        type Weekday = chrono::Weekday;
+
+        fn day_of_week(&self) -> chrono::Weekday {
+            self.weekday()
+        }
+    }
+
+    impl<Tz: chrono::TimeZone> TimeOfDay for chrono::DateTime<Tz> {
+        fn hour_minute(&self) -> (u32, u32) {
+            (self.hour(), self.minute())
+        }
+    }
+
+    impl TimeOfDay for chrono::NaiveDateTime {
+        fn hour_minute(&self) -> (u32, u32) {
+            (self.hour(), self.minute())
+        }
+    }
+
+    impl TimeOfDay for chrono::NaiveTime {
+        fn hour_minute(&self) -> (u32, u32) {
+            (self.hour(), self.minute())
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::{DayOfWeek, TimeOfDay};
+
+    impl DayOfWeek for ::time::OffsetDateTime {
+        type Weekday = ::time::Weekday;
+
+        fn day_of_week(&self) -> ::time::Weekday {
+            self.weekday()
+        }
+    }
+
+    impl DayOfWeek for ::time::PrimitiveDateTime {
+        type Weekday = ::time::Weekday;
+
+        fn day_of_week(&self) -> ::time::Weekday {
+            self.weekday()
+        }
+    }
+
+    impl DayOfWeek for ::time::Date {
+        type Weekday = ::time::Weekday;
+
+        fn day_of_week(&self) -> ::time::Weekday {
+            self.weekday()
+        }
+    }
+
+    impl TimeOfDay for ::time::OffsetDateTime {
+        fn hour_minute(&self) -> (u32, u32) {
+            (self.hour().into(), self.minute().into())
+        }
+    }
+
+    impl TimeOfDay for ::time::PrimitiveDateTime {
+        fn hour_minute(&self) -> (u32, u32) {
+            (self.hour().into(), self.minute().into())
+        }
+    }
+
+    impl TimeOfDay for ::time::Time {
+        fn hour_minute(&self) -> (u32, u32) {
+            (self.hour().into(), self.minute().into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "chrono")]
+    mod chrono_tests {
+        use crate::rules::{Rule, TimeWindow, Weekdays};
+        use chrono::prelude::*;
+        use claim::{assert_err, assert_ok};
+
+        #[test]
+        fn weekdays() {
+            let weekend = Weekdays(&[Weekday::Sat, Weekday::Sun]);
+            assert_ok!(weekend.check(&NaiveDate::from_ymd(2022, 6, 5)));
+            assert_ok!(weekend.check(&Utc.ymd(2022, 6, 4).and_hms(23, 0, 0)));
+            let res_error_message = weekend
+                .check(&NaiveDate::from_ymd(2022, 6, 6).and_hms(0, 0, 0))
+                .expect_err("Should be an Err");
+            assert_eq!(res_error_message, "The day of the week is not allowed");
+        }
+        #[test]
+        fn time_window() {
+            let office = TimeWindow((9, 0), (17, 30));
+            assert_ok!(office.check(&NaiveTime::from_hms(9, 0, 0)));
+            assert_err!(office.check(&NaiveTime::from_hms(8, 59, 59)));
+            let night = TimeWindow((22, 0), (6, 0));
+            assert_ok!(night.check(&NaiveTime::from_hms(23, 0, 0)));
+            assert_ok!(night.check(&NaiveTime::from_hms(5, 59, 0)));
+            let res_error_message = night
+                .check(&FixedOffset::east(3600).ymd(2022, 6, 1).and_hms(12, 0, 0))
+                .expect_err("Should be an Err");
+            assert_eq!(res_error_message, "The time is outside of the window");
+        }
+        #[test]
+        fn time_window_whole_day() {
+            let all_day = TimeWindow((9, 0), (9, 0));
+            assert_ok!(all_day.check(&NaiveTime::from_hms(9, 0, 0)));
+            assert_ok!(all_day.check(&NaiveTime::from_hms(8, 59, 59)));
+        }
+        #[test]
+        fn time_window_invalid_bounds() {
+            let res_error_message = TimeWindow((9, 0), (24, 0))
+                .check(&NaiveTime::from_hms(12, 0, 0))
+                .expect_err("Should be an Err");
+            assert_eq!(res_error_message, "The time 24:00 is not valid");
+            assert_err!(TimeWindow((9, 60), (17, 0)).check(&NaiveTime::from_hms(12, 0, 0)));
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod time_tests {
+        use crate::rules::{Rule, TimeWindow, Weekdays};
+        use ::time::macros::{date, datetime, time};
+        use ::time::Weekday;
+        use claim::{assert_err, assert_ok};
+
+        #[test]
+        fn weekdays() {
+            let weekend = Weekdays(&[Weekday::Saturday, Weekday::Sunday]);
+            assert_ok!(weekend.check(&date!(2022 - 06 - 05)));
+            assert_err!(weekend.check(&datetime!(2022-06-06 00:00 UTC)));
+        }
+        #[test]
+        fn time_window() {
+            let night = TimeWindow((22, 0), (6, 0));
+            assert_ok!(night.check(&time!(22:00)));
+            assert_err!(night.check(&datetime!(2022-06-01 06:00)));
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use super::Rule;
+
+/// Brand of a payment card, recognized by the prefix
+/// and the length of its number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    AmericanExpress,
+    Discover,
+    DinersClub,
+    Jcb,
+    UnionPay,
+}
+
+impl CardBrand {
+    /// Recognize the brand of a card number made of digits only
+    pub fn of(number: &str) -> Option<CardBrand> {
+        let prefix = |length: usize| number.get(..length).and_then(|p| p.parse::<u32>().ok());
+        let length = number.len();
+        let brand = match (prefix(1)?, prefix(2)?, prefix(3)?, prefix(4)?) {
+            (4, ..) if matches!(length, 13 | 16 | 19) => CardBrand::Visa,
+            (_, 51..=55, ..) | (.., 2221..=2720) if length == 16 => CardBrand::Mastercard,
+            (_, 34 | 37, ..) if length == 15 => CardBrand::AmericanExpress,
+            (.., 6011) | (_, _, 644..=649, _) | (_, 65, ..) if (16..=19).contains(&length) => {
+                CardBrand::Discover
+            }
+            (.., 3528..=3589) if (16..=19).contains(&length) => CardBrand::Jcb,
+            (_, 36 | 38 | 39, ..) | (_, _, 300..=305, _) if (14..=19).contains(&length) => {
+                CardBrand::DinersClub
+            }
+            (_, 62, ..) if (16..=19).contains(&length) => CardBrand::UnionPay,
+            _ => return None,
+        };
+        Some(brand)
+    }
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a payment card number
+///
+/// 12 to 19 digits, optionally separated by spaces or hyphens, with
+/// a valid Luhn checksum. The number can be restricted to some brands.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Card(
+///     #[rule(CardNumber::new().brands(&[CardBrand::Visa, CardBrand::Mastercard]))]
+///     String
+/// );
+///
+/// assert!(Card(String::from("4111 1111 1111 1111")).check_validity().is_ok());
+/// assert!(Card(String::from("4111 1111 1111 1112")).check_validity().is_err());
+/// assert!(Card(String::from("3782 822463 10005")).check_validity().is_err());
+/// ```
+pub struct CardNumber<'a> {
+    brands: Option<&'a [CardBrand]>,
+}
+
+impl<'a> CardNumber<'a> {
+    /// Accept the card numbers of any brand
+    pub const fn new() -> Self {
+        CardNumber { brands: None }
+    }
+
+    /// Only accept the card numbers of these `brands`
+    pub const fn brands(self, brands: &'a [CardBrand]) -> Self {
+        CardNumber {
+            brands: Some(brands),
+        }
+    }
+}
+
+impl<'a> Default for CardNumber<'a> {
+    fn default() -> Self {
+        CardNumber::new()
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for CardNumber<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let number: String = value.chars().filter(|c| *c != ' ' && *c != '-').collect();
+        let valid_separators = !value.starts_with([' ', '-']) && !value.ends_with([' ', '-']);
+        if !valid_separators
+            || !(12..=19).contains(&number.len())
+            || !number.bytes().all(|c| c.is_ascii_digit())
+        {
+            return Err(String::from("The card number format is invalid"));
+        }
+        if !luhn(&number) {
+            return Err(String::from("The card number checksum is invalid"));
+        }
+        if let Some(brands) = self.brands {
+            match CardBrand::of(&number) {
+                Some(brand) if brands.contains(&brand) => {}
+                _ => return Err(String::from("The card brand is not allowed")),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Luhn checksum of a string of digits
+fn luhn(digits: &str) -> bool {
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .map(|c| u32::from(c - b'0'))
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            0 => digit,
+            _ if digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{CardBrand, CardNumber, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn card_number_ok() {
+        assert_ok!(CardNumber::new().check("4111111111111111"));
+        assert_ok!(CardNumber::new().check("3782-822463-10005"));
+    }
+    #[test]
+    fn card_number_format_err() {
+        let res_error_message = CardNumber::new()
+            .check("4111 1111 1111 111a")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The card number format is invalid");
+        assert_err!(CardNumber::new().check("4111"));
+        assert_err!(CardNumber::new().check(" 4111111111111111"));
+    }
+    #[test]
+    fn card_number_checksum_err() {
+        let res_error_message = CardNumber::new()
+            .check("4111111111111112")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The card number checksum is invalid");
+    }
+    #[test]
+    fn card_brand() {
+        assert_eq!(CardBrand::of("4111111111111111"), Some(CardBrand::Visa));
+        assert_eq!(
+            CardBrand::of("5555555555554444"),
+            Some(CardBrand::Mastercard)
+        );
+        assert_eq!(
+            CardBrand::of("2223003122003222"),
+            Some(CardBrand::Mastercard)
+        );
+        assert_eq!(
+            CardBrand::of("378282246310005"),
+            Some(CardBrand::AmericanExpress)
+        );
+        assert_eq!(CardBrand::of("6011111111111117"), Some(CardBrand::Discover));
+        assert_eq!(CardBrand::of("3530111333300000"), Some(CardBrand::Jcb));
+        assert_eq!(CardBrand::of("30569309025904"), Some(CardBrand::DinersClub));
+    }
+    #[test]
+    fn card_number_brand_err() {
+        let rule = CardNumber::new().brands(&[CardBrand::Visa]);
+        let res_error_message = rule
+            .check("5555555555554444")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The card brand is not allowed");
+    }
+}
@@ -0,0 +1,315 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not contain uppercase letters
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Tag(#[rule(Lowercase())] String);
+///
+/// assert!(Tag(String::from("rust-2021")).check_validity().is_ok());
+/// assert!(Tag(String::from("Rust")).check_validity().is_err());
+/// ```
+pub struct Lowercase();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not contain lowercase letters
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct CurrencyCode(#[rule(Uppercase())] String);
+///
+/// assert!(CurrencyCode(String::from("EUR")).check_validity().is_ok());
+/// assert!(CurrencyCode(String::from("Eur")).check_validity().is_err());
+/// ```
+pub struct Uppercase();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be in `snake_case`
+///
+/// ASCII lowercase letters and digits, starting with a letter, with
+/// words separated by single underscores
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct ConfigKey(#[rule(SnakeCase())] String);
+///
+/// assert!(ConfigKey(String::from("max_connections")).check_validity().is_ok());
+/// assert!(ConfigKey(String::from("maxConnections")).check_validity().is_err());
+/// ```
+pub struct SnakeCase();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be in `kebab-case`
+///
+/// ASCII lowercase letters and digits, starting with a letter, with
+/// words separated by single hyphens
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct CssClass(#[rule(KebabCase())] String);
+///
+/// assert!(CssClass(String::from("nav-bar")).check_validity().is_ok());
+/// assert!(CssClass(String::from("nav--bar")).check_validity().is_err());
+/// ```
+pub struct KebabCase();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be in `camelCase`
+///
+/// ASCII letters and digits, starting with a lowercase letter
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct JsonField(#[rule(CamelCase())] String);
+///
+/// assert!(JsonField(String::from("createdAt")).check_validity().is_ok());
+/// assert!(JsonField(String::from("created_at")).check_validity().is_err());
+/// ```
+pub struct CamelCase();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be in `PascalCase`
+///
+/// ASCII letters and digits, starting with an uppercase letter
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct TypeName(#[rule(PascalCase())] String);
+///
+/// assert!(TypeName(String::from("HttpClient")).check_validity().is_ok());
+/// assert!(TypeName(String::from("httpClient")).check_validity().is_err());
+/// ```
+pub struct PascalCase();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an URL slug
+///
+/// ASCII lowercase letters and digits with words separated
+/// by single hyphens
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Article(#[rule(Slug())] String);
+///
+/// assert!(Article(String::from("2022-rust-survey")).check_validity().is_ok());
+/// assert!(Article(String::from("rust survey")).check_validity().is_err());
+/// ```
+pub struct Slug();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an identifier of a programming language
+///
+/// ASCII letters, digits and underscores, not starting with a digit and
+/// not only an underscore. Some reserved words can be rejected.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Variable(#[rule(Identifier::new().reserved_words(&["fn", "let", "match"]))] String);
+///
+/// assert!(Variable(String::from("_user_id")).check_validity().is_ok());
+/// assert!(Variable(String::from("1user")).check_validity().is_err());
+/// assert!(Variable(String::from("match")).check_validity().is_err());
+/// ```
+pub struct Identifier<'a> {
+    reserved_words: &'a [&'a str],
+}
+
+impl<'a> Identifier<'a> {
+    /// Accept any identifier
+    pub const fn new() -> Self {
+        Identifier {
+            reserved_words: &[],
+        }
+    }
+
+    /// Reject the identifiers that are one of the `reserved_words`
+    pub const fn reserved_words(self, reserved_words: &'a [&'a str]) -> Self {
+        Identifier { reserved_words }
+    }
+}
+
+impl<'a> Default for Identifier<'a> {
+    fn default() -> Self {
+        Identifier::new()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Lowercase {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match value.as_ref().chars().any(char::is_uppercase) {
+            true => Err(String::from("The value is not in lowercase")),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Uppercase {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match value.as_ref().chars().any(char::is_lowercase) {
+            true => Err(String::from("The value is not in uppercase")),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for SnakeCase {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        match value.starts_with(|c: char| c.is_ascii_lowercase()) && is_words(value, '_') {
+            true => Ok(()),
+            false => Err(String::from("The value is not in snake_case")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for KebabCase {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        match value.starts_with(|c: char| c.is_ascii_lowercase()) && is_words(value, '-') {
+            true => Ok(()),
+            false => Err(String::from("The value is not in kebab-case")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for CamelCase {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let valid = value.starts_with(|c: char| c.is_ascii_lowercase())
+            && value.bytes().all(|c| c.is_ascii_alphanumeric());
+        match valid {
+            true => Ok(()),
+            false => Err(String::from("The value is not in camelCase")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for PascalCase {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let valid = value.starts_with(|c: char| c.is_ascii_uppercase())
+            && value.bytes().all(|c| c.is_ascii_alphanumeric());
+        match valid {
+            true => Ok(()),
+            false => Err(String::from("The value is not in PascalCase")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Slug {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match is_words(value.as_ref(), '-') {
+            true => Ok(()),
+            false => Err(String::from("The value is not a slug")),
+        }
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for Identifier<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let valid = value != "_"
+            && value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && value
+                .bytes()
+                .all(|c| c.is_ascii_alphanumeric() || c == b'_');
+        if !valid {
+            return Err(String::from("The value is not an identifier"));
+        }
+        match self.reserved_words.contains(&value) {
+            true => Err(format!("The identifier {:?} is a reserved word", value)),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Non-empty words of ASCII lowercase letters and digits
+/// separated by single `separator`
+fn is_words(value: &str, separator: char) -> bool {
+    value.split(separator).all(|word| {
+        !word.is_empty()
+            && word
+                .bytes()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{
+        CamelCase, Identifier, KebabCase, Lowercase, PascalCase, Rule, Slug, SnakeCase, Uppercase,
+    };
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn lowercase_uppercase() {
+        assert_ok!(Lowercase().check("été 2022"));
+        assert_err!(Lowercase().check("Été"));
+        assert_ok!(Uppercase().check("ÉTÉ 2022"));
+        let res_error_message = Uppercase().check("ÉTé").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The value is not in uppercase");
+    }
+    #[test]
+    fn snake_and_kebab_case() {
+        assert_ok!(SnakeCase().check("http2_port"));
+        assert_err!(SnakeCase().check("_port"));
+        assert_err!(SnakeCase().check("port_"));
+        assert_err!(SnakeCase().check("2_port"));
+        assert_ok!(KebabCase().check("http2-port"));
+        let res_error_message = KebabCase()
+            .check("http_port")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The value is not in kebab-case");
+    }
+    #[test]
+    fn camel_and_pascal_case() {
+        assert_ok!(CamelCase().check("httpPort2"));
+        assert_err!(CamelCase().check("HttpPort"));
+        assert_ok!(PascalCase().check("HttpPort"));
+        assert_err!(PascalCase().check("Http-Port"));
+        assert_err!(PascalCase().check(""));
+    }
+    #[test]
+    fn slug() {
+        assert_ok!(Slug().check("42-answers"));
+        assert_err!(Slug().check("-answers"));
+        assert_err!(Slug().check(""));
+    }
+    #[test]
+    fn identifier() {
+        let rule = Identifier::new().reserved_words(&["type"]);
+        assert_ok!(rule.check("_type"));
+        assert_err!(rule.check("_"));
+        assert_err!(rule.check("my-type"));
+        let res_error_message = rule.check("type").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The identifier \"type\" is a reserved word"
+        );
+    }
+}
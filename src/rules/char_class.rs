@@ -0,0 +1,363 @@
+use super::Rule;
+use std::ops::RangeInclusive;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to only contain ASCII characters
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Code(#[rule(Ascii())] String);
+///
+/// assert!(Code(String::from("AB-12")).check_validity().is_ok());
+/// assert!(Code(String::from("AB-1²")).check_validity().is_err());
+/// ```
+pub struct Ascii();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to only contain letters and digits
+///
+/// The Unicode letters and digits are accepted, combine it with [`Ascii`]
+/// to only accept the ASCII ones
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Username(#[rule(Alphanumeric())] String);
+///
+/// assert!(Username(String::from("Zoé42")).check_validity().is_ok());
+/// assert!(Username(String::from("zoe_42")).check_validity().is_err());
+/// ```
+pub struct Alphanumeric();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to only contain the ASCII digits `0` to `9`
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Pin(#[rule(Digits(), MinMaxLength(4, 6))] String);
+///
+/// assert!(Pin(String::from("0042")).check_validity().is_ok());
+/// assert!(Pin(String::from("00 42")).check_validity().is_err());
+/// ```
+pub struct Digits();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to only contain printable characters
+///
+/// The space is printable but the control characters, the other
+/// whitespaces and the invisible formatting characters such as the
+/// zero width space or the byte order mark are not
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Title(#[rule(Printable())] String);
+///
+/// assert!(Title(String::from("Hello world!")).check_validity().is_ok());
+/// assert!(Title(String::from("Hello\u{200B}world!")).check_validity().is_err());
+/// ```
+pub struct Printable();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not contain control characters,
+/// including tabulations and line breaks
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Name(#[rule(NoControlChars())] String);
+///
+/// assert!(Name(String::from("John Smith")).check_validity().is_ok());
+/// assert!(Name(String::from("John\nSmith")).check_validity().is_err());
+/// ```
+pub struct NoControlChars();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not contain whitespaces
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Token(#[rule(NoWhitespace())] String);
+///
+/// assert!(Token(String::from("a1b2c3")).check_validity().is_ok());
+/// assert!(Token(String::from("a1 b2c3")).check_validity().is_err());
+/// ```
+pub struct NoWhitespace();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not start or end with a whitespace
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Name(#[rule(NoLeadingTrailingWhitespace())] String);
+///
+/// assert!(Name(String::from("John Smith")).check_validity().is_ok());
+/// assert!(Name(String::from("John Smith ")).check_validity().is_err());
+/// ```
+pub struct NoLeadingTrailingWhitespace();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to only contain the characters of a set
+///
+/// The set can be a `&str` or a slice of its characters, a range of
+/// characters or a predicate
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Dna(#[rule(Charset("ACGT"))] String);
+///
+/// #[derive(Validator)]
+/// struct Letters(#[rule(Charset('a'..='z'))] String);
+///
+/// #[derive(Validator)]
+/// struct Hex(#[rule(Charset(|c: char| c.is_ascii_hexdigit()))] String);
+///
+/// assert!(Dna(String::from("GATTACA")).check_validity().is_ok());
+/// assert!(Dna(String::from("GATTACU")).check_validity().is_err());
+/// assert!(Letters(String::from("abc")).check_validity().is_ok());
+/// assert!(Hex(String::from("c0ffee")).check_validity().is_ok());
+/// ```
+pub struct Charset<S>(pub S);
+
+/// Define a set of characters used by [`Charset`]
+pub trait AllowedChars {
+    fn allows(&self, c: char) -> bool;
+}
+
+impl AllowedChars for &str {
+    fn allows(&self, c: char) -> bool {
+        self.contains(c)
+    }
+}
+
+impl AllowedChars for &[char] {
+    fn allows(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl<const N: usize> AllowedChars for [char; N] {
+    fn allows(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl AllowedChars for RangeInclusive<char> {
+    fn allows(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl<F: Fn(char) -> bool> AllowedChars for F {
+    fn allows(&self, c: char) -> bool {
+        self(c)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Ascii {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(value.as_ref(), |c| c.is_ascii(), "is not ASCII")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Alphanumeric {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(value.as_ref(), char::is_alphanumeric, "is not alphanumeric")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Digits {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(value.as_ref(), |c| c.is_ascii_digit(), "is not a digit")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Printable {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(value.as_ref(), is_printable, "is not printable")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for NoControlChars {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(
+            value.as_ref(),
+            |c| !c.is_control(),
+            "is a control character",
+        )
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for NoWhitespace {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(value.as_ref(), |c| !c.is_whitespace(), "is a whitespace")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for NoLeadingTrailingWhitespace {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        match value.chars().next() {
+            Some(c) if c.is_whitespace() => {
+                return Err(char_error(c, 0, "is a leading whitespace"));
+            }
+            _ => {}
+        }
+        match value.chars().last() {
+            Some(c) if c.is_whitespace() => {
+                let position = value.chars().count() - 1;
+                Err(char_error(c, position, "is a trailing whitespace"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<S: AllowedChars, T: AsRef<str> + ?Sized> Rule<T> for Charset<S> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(value.as_ref(), |c| self.0.allows(c), "is not allowed")
+    }
+}
+
+/// Report the first character that doesn't match the predicate
+/// with its position, counted in characters from 0
+pub(crate) fn check_chars(
+    value: &str,
+    predicate: impl Fn(char) -> bool,
+    reason: &str,
+) -> Result<(), String> {
+    match value.chars().enumerate().find(|(_, c)| !predicate(*c)) {
+        Some((position, c)) => Err(char_error(c, position, reason)),
+        None => Ok(()),
+    }
+}
+
+fn char_error(c: char, position: usize, reason: &str) -> String {
+    format!("The character {:?} at position {} {}", c, position, reason)
+}
+
+/// Bidirectional formatting characters: the marks, embeddings,
+/// overrides and isolates that change the display order of the text
+pub(crate) const BIDI_CONTROLS: &[RangeInclusive<char>] = &[
+    '\u{061C}'..='\u{061C}',
+    '\u{200E}'..='\u{200F}',
+    '\u{202A}'..='\u{202E}',
+    '\u{2066}'..='\u{2069}',
+];
+
+/// Zero width characters, that are not displayed but are not whitespaces
+pub(crate) const ZERO_WIDTH_CHARS: &[RangeInclusive<char>] = &[
+    '\u{180E}'..='\u{180E}',
+    '\u{200B}'..='\u{200D}',
+    '\u{2060}'..='\u{2064}',
+    '\u{FEFF}'..='\u{FEFF}',
+];
+
+/// Check if a character is in a table of [`BIDI_CONTROLS`] or [`ZERO_WIDTH_CHARS`]
+pub(crate) fn in_table(table: &[RangeInclusive<char>], c: char) -> bool {
+    table.iter().any(|range| range.contains(&c))
+}
+
+fn is_printable(c: char) -> bool {
+    c == ' '
+        || !(c.is_control()
+            || c.is_whitespace()
+            || in_table(BIDI_CONTROLS, c)
+            || in_table(ZERO_WIDTH_CHARS, c)
+            || matches!(
+                c,
+                '\u{AD}' | '\u{2065}' | '\u{206A}'..='\u{206F}' | '\u{FFF9}'..='\u{FFFB}'
+            ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{
+        Alphanumeric, Ascii, Charset, Digits, NoControlChars, NoLeadingTrailingWhitespace,
+        NoWhitespace, Printable, Rule,
+    };
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn ascii() {
+        assert_ok!(Ascii().check("Hello!"));
+        let res_error_message = Ascii().check("Héllo").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The character 'é' at position 1 is not ASCII"
+        );
+    }
+    #[test]
+    fn alphanumeric_and_digits() {
+        assert_ok!(Alphanumeric().check("abc123"));
+        assert_err!(Alphanumeric().check("abc-123"));
+        assert_ok!(Digits().check("0123"));
+        let res_error_message = Digits().check("01²3").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The character '²' at position 2 is not a digit"
+        );
+    }
+    #[test]
+    fn printable_and_control_chars() {
+        assert_ok!(Printable().check("a b"));
+        assert_err!(Printable().check("a\u{A0}b"));
+        assert_err!(Printable().check("\u{FEFF}ab"));
+        assert_err!(Printable().check("a\u{61C}b"));
+        assert_err!(Printable().check("a\u{180E}b"));
+        assert_ok!(NoControlChars().check("a\u{A0}b"));
+        let res_error_message = NoControlChars()
+            .check("a\tb")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The character '\\t' at position 1 is a control character"
+        );
+    }
+    #[test]
+    fn whitespaces() {
+        assert_err!(NoWhitespace().check("a b"));
+        assert_ok!(NoLeadingTrailingWhitespace().check("a b"));
+        assert_ok!(NoLeadingTrailingWhitespace().check(""));
+        let res_error_message = NoLeadingTrailingWhitespace()
+            .check("ab\n")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The character '\\n' at position 2 is a trailing whitespace"
+        );
+    }
+    #[test]
+    fn charset() {
+        assert_ok!(Charset("abc").check("cab"));
+        assert_ok!(Charset(['a', 'b']).check("abba"));
+        assert_ok!(Charset('0'..='7').check("0755"));
+        assert_ok!(Charset(|c: char| c != '/').check("a.b"));
+        let res_error_message = Charset("abc").check("abd").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The character 'd' at position 2 is not allowed"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use super::Rule;
+use std::net::IpAddr;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a CIDR block such as `192.168.0.0/16`
+///
+/// The prefix length can be limited for each IP version, and the
+/// block can be required to not have any bit set after the prefix
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Subnet(#[rule(Cidr::new().ipv4_prefix(16, 30).strict())] String);
+///
+/// assert!(Subnet(String::from("10.1.0.0/16")).check_validity().is_ok());
+/// assert!(Subnet(String::from("10.0.0.0/8")).check_validity().is_err());
+/// assert!(Subnet(String::from("10.1.0.1/16")).check_validity().is_err());
+/// ```
+pub struct Cidr {
+    ipv4_prefix: (u8, u8),
+    ipv6_prefix: (u8, u8),
+    strict: bool,
+}
+
+impl Cidr {
+    /// Accept any CIDR block
+    pub const fn new() -> Self {
+        Cidr {
+            ipv4_prefix: (0, 32),
+            ipv6_prefix: (0, 128),
+            strict: false,
+        }
+    }
+
+    /// Limit the prefix length of the IPv4 blocks
+    pub const fn ipv4_prefix(self, min: u8, max: u8) -> Self {
+        Cidr {
+            ipv4_prefix: (min, max),
+            ..self
+        }
+    }
+
+    /// Limit the prefix length of the IPv6 blocks
+    pub const fn ipv6_prefix(self, min: u8, max: u8) -> Self {
+        Cidr {
+            ipv6_prefix: (min, max),
+            ..self
+        }
+    }
+
+    /// Reject the blocks with bits set after the prefix such as `10.0.0.1/8`
+    pub const fn strict(self) -> Self {
+        Cidr {
+            strict: true,
+            ..self
+        }
+    }
+}
+
+impl Default for Cidr {
+    fn default() -> Self {
+        Cidr::new()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Cidr {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (ip, prefix) = parse_cidr(value.as_ref())
+            .ok_or_else(|| String::from("The value is not a CIDR block"))?;
+        let ((min, max), host_bits) = match ip {
+            IpAddr::V4(ip) => {
+                let bits = u32::from(ip) as u128;
+                (self.ipv4_prefix, host_bits(bits, 32, prefix))
+            }
+            IpAddr::V6(ip) => (self.ipv6_prefix, host_bits(u128::from(ip), 128, prefix)),
+        };
+        if prefix < min {
+            return Err(String::from("The prefix length is too short"));
+        }
+        if prefix > max {
+            return Err(String::from("The prefix length is too long"));
+        }
+        if self.strict && host_bits != 0 {
+            return Err(String::from("The CIDR block has bits set after the prefix"));
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_cidr(value: &str) -> Option<(IpAddr, u8)> {
+    let (ip, prefix) = value.split_once('/')?;
+    let ip: IpAddr = ip.parse().ok()?;
+    if prefix.is_empty() || !prefix.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let prefix: u8 = prefix.parse().ok()?;
+    let max_prefix = if ip.is_ipv4() { 32 } else { 128 };
+    match prefix <= max_prefix {
+        true => Some((ip, prefix)),
+        false => None,
+    }
+}
+
+/// Check if `ip` is in the CIDR block `network/prefix`, an IPv4
+/// address is never in an IPv6 block
+#[cfg(feature = "url")]
+pub(crate) fn cidr_contains((network, prefix): (IpAddr, u8), ip: IpAddr) -> bool {
+    let (network, ip, length) = match (network, ip) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => {
+            (u32::from(network) as u128, u32::from(ip) as u128, 32)
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip)) => (u128::from(network), u128::from(ip), 128),
+        _ => return false,
+    };
+    network ^ host_bits(network, length, prefix) == ip ^ host_bits(ip, length, prefix)
+}
+
+/// Bits of an address of `length` bits after its `prefix`
+fn host_bits(bits: u128, length: u8, prefix: u8) -> u128 {
+    let host_length = u32::from(length - prefix);
+    match host_length {
+        0 => 0,
+        128 => bits,
+        _ => bits & ((1u128 << host_length) - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Cidr, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn cidr_ok() {
+        assert_ok!(Cidr::new().check("0.0.0.0/0"));
+        assert_ok!(Cidr::new().check("10.0.0.1/32"));
+        assert_ok!(Cidr::new().check("2001:db8::/32"));
+    }
+    #[test]
+    fn cidr_err() {
+        assert_err!(Cidr::new().check("10.0.0.1"));
+        assert_err!(Cidr::new().check("10.0.0.1/33"));
+        assert_err!(Cidr::new().check("10.0.0.1/+8"));
+        assert_err!(Cidr::new().check("2001:db8::/129"));
+    }
+    #[test]
+    fn cidr_prefix_err() {
+        let rule = Cidr::new().ipv6_prefix(48, 64);
+        let res_error_message = rule.check("2001:db8::/32").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The prefix length is too short");
+    }
+    #[test]
+    fn cidr_strict() {
+        assert_ok!(Cidr::new().strict().check("2001:db8::/32"));
+        assert_err!(Cidr::new().strict().check("192.168.1.1/24"));
+    }
+}
@@ -0,0 +1,344 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to contain a substring
+///
+/// Wrap it in [`IgnoreCase`] to ignore the case
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Query(#[rule(Contains("="))] String);
+///
+/// assert!(Query(String::from("page=1")).check_validity().is_ok());
+/// assert!(Query(String::from("page")).check_validity().is_err());
+/// ```
+pub struct Contains<'a>(pub &'a str);
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not contain a substring
+///
+/// Wrap it in [`IgnoreCase`] to ignore the case
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Path(#[rule(NotContains(".."))] String);
+///
+/// assert!(Path(String::from("images/logo.png")).check_validity().is_ok());
+/// assert!(Path(String::from("../etc/passwd")).check_validity().is_err());
+/// ```
+pub struct NotContains<'a>(pub &'a str);
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to start with a prefix
+///
+/// Wrap it in [`IgnoreCase`] to ignore the case
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct ApiKey(#[rule(StartsWith("sk_"))] String);
+///
+/// assert!(ApiKey(String::from("sk_12345")).check_validity().is_ok());
+/// assert!(ApiKey(String::from("pk_12345")).check_validity().is_err());
+/// ```
+pub struct StartsWith<'a>(pub &'a str);
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to end with a suffix
+///
+/// Wrap it in [`IgnoreCase`] to ignore the case
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Image(#[rule(IgnoreCase(EndsWith(".png")))] String);
+///
+/// assert!(Image(String::from("logo.PNG")).check_validity().is_ok());
+/// assert!(Image(String::from("logo.jpg")).check_validity().is_err());
+/// ```
+pub struct EndsWith<'a>(pub &'a str);
+
+/// Rule to apply [`Contains`], [`NotContains`], [`StartsWith`] or
+/// [`EndsWith`] without considering the case
+///
+/// Both the value and the pattern are converted to lowercase
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Comment(#[rule(IgnoreCase(NotContains("spam")))] String);
+///
+/// assert!(Comment(String::from("Hello")).check_validity().is_ok());
+/// assert!(Comment(String::from("Buy SPAM")).check_validity().is_err());
+/// ```
+pub struct IgnoreCase<T>(pub T);
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for Contains<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_contains(value.as_ref(), self.0)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for NotContains<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_not_contains(value.as_ref(), self.0)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for StartsWith<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_starts_with(value.as_ref(), self.0)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for EndsWith<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_ends_with(value.as_ref(), self.0)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for IgnoreCase<Contains<'a>> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (value, pattern) = lowercase(value.as_ref(), self.0 .0);
+        check_contains(&value, &pattern)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for IgnoreCase<NotContains<'a>> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (value, pattern) = lowercase(value.as_ref(), self.0 .0);
+        check_not_contains(&value, &pattern)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for IgnoreCase<StartsWith<'a>> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (value, pattern) = lowercase(value.as_ref(), self.0 .0);
+        check_starts_with(&value, &pattern)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for IgnoreCase<EndsWith<'a>> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (value, pattern) = lowercase(value.as_ref(), self.0 .0);
+        check_ends_with(&value, &pattern)
+    }
+}
+
+fn lowercase(value: &str, pattern: &str) -> (String, String) {
+    (value.to_lowercase(), pattern.to_lowercase())
+}
+
+fn check_contains(value: &str, pattern: &str) -> Result<(), String> {
+    match value.contains(pattern) {
+        true => Ok(()),
+        false => Err(format!("The value doesn't contain {:?}", pattern)),
+    }
+}
+
+fn check_not_contains(value: &str, pattern: &str) -> Result<(), String> {
+    match value.contains(pattern) {
+        true => Err(format!("The value contains {:?}", pattern)),
+        false => Ok(()),
+    }
+}
+
+fn check_starts_with(value: &str, pattern: &str) -> Result<(), String> {
+    match value.starts_with(pattern) {
+        true => Ok(()),
+        false => Err(format!("The value doesn't start with {:?}", pattern)),
+    }
+}
+
+fn check_ends_with(value: &str, pattern: &str) -> Result<(), String> {
+    match value.ends_with(pattern) {
+        true => Ok(()),
+        false => Err(format!("The value doesn't end with {:?}", pattern)),
+    }
+}
+
+/// Rule to constrain an iterable collection (with reference)
+/// to contain an item
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Roles(#[rule(ContainsItem("user"))] Vec<String>);
+///
+/// assert!(Roles(vec![String::from("user"), String::from("admin")]).check_validity().is_ok());
+/// assert!(Roles(vec![String::from("admin")]).check_validity().is_err());
+/// ```
+pub struct ContainsItem<T>(pub T);
+
+/// Rule to constrain an iterable collection (with reference)
+/// to not contain an item
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Scores(#[rule(NotContainsItem(0))] Vec<u32>);
+///
+/// assert!(Scores(vec![1, 2, 3]).check_validity().is_ok());
+/// assert!(Scores(vec![1, 0, 3]).check_validity().is_err());
+/// ```
+pub struct NotContainsItem<T>(pub T);
+
+/// Rule to constrain an iterable collection (with reference)
+/// to start with an item
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Command(#[rule(StartsWithItem("git"))] Vec<&'static str>);
+///
+/// assert!(Command(vec!["git", "status"]).check_validity().is_ok());
+/// assert!(Command(vec!["rm", "-rf"]).check_validity().is_err());
+/// ```
+pub struct StartsWithItem<T>(pub T);
+
+/// Rule to constrain an iterable collection (with reference)
+/// to end with an item
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Path(#[rule(EndsWithItem("index.html"))] Vec<String>);
+///
+/// assert!(Path(vec![String::from("docs"), String::from("index.html")]).check_validity().is_ok());
+/// ```
+pub struct EndsWithItem<T>(pub T);
+
+impl<T, U> Rule<U> for ContainsItem<T>
+where
+    U: IntoIterator,
+    for<'b> &'b U: IntoIterator<Item = &'b U::Item>,
+    U::Item: PartialEq<T>,
+{
+    fn check(&self, value: &U) -> Result<(), String> {
+        match value.into_iter().any(|v| *v == self.0) {
+            true => Ok(()),
+            false => Err(String::from(
+                "The collection doesn't contain the expected item",
+            )),
+        }
+    }
+}
+
+impl<T, U> Rule<U> for NotContainsItem<T>
+where
+    U: IntoIterator,
+    for<'b> &'b U: IntoIterator<Item = &'b U::Item>,
+    U::Item: PartialEq<T>,
+{
+    fn check(&self, value: &U) -> Result<(), String> {
+        match value.into_iter().any(|v| *v == self.0) {
+            true => Err(String::from("The collection contains a forbidden item")),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<T, U> Rule<U> for StartsWithItem<T>
+where
+    U: IntoIterator,
+    for<'b> &'b U: IntoIterator<Item = &'b U::Item>,
+    U::Item: PartialEq<T>,
+{
+    fn check(&self, value: &U) -> Result<(), String> {
+        match value.into_iter().next() {
+            Some(v) if *v == self.0 => Ok(()),
+            _ => Err(String::from(
+                "The collection doesn't start with the expected item",
+            )),
+        }
+    }
+}
+
+impl<T, U> Rule<U> for EndsWithItem<T>
+where
+    U: IntoIterator,
+    for<'b> &'b U: IntoIterator<Item = &'b U::Item>,
+    U::Item: PartialEq<T>,
+{
+    fn check(&self, value: &U) -> Result<(), String> {
+        match value.into_iter().last() {
+            Some(v) if *v == self.0 => Ok(()),
+            _ => Err(String::from(
+                "The collection doesn't end with the expected item",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{
+        Contains, ContainsItem, EndsWith, EndsWithItem, IgnoreCase, NotContains, NotContainsItem,
+        Rule, StartsWith, StartsWithItem,
+    };
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn contains() {
+        assert_ok!(Contains("ell").check("Hello"));
+        let res_error_message = Contains("ELL")
+            .check("Hello")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The value doesn't contain \"ELL\"");
+        assert_ok!(IgnoreCase(Contains("ELL")).check("Hello"));
+    }
+    #[test]
+    fn not_contains() {
+        assert_ok!(NotContains("..").check("a/b"));
+        let res_error_message = NotContains("..")
+            .check("../b")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The value contains \"..\"");
+        assert_err!(IgnoreCase(NotContains("Spam")).check("SPAM"));
+    }
+    #[test]
+    fn starts_and_ends_with() {
+        assert_ok!(StartsWith("https://").check("https://example.com"));
+        assert_err!(StartsWith("https://").check("HTTPS://example.com"));
+        assert_ok!(IgnoreCase(StartsWith("https://")).check("HTTPS://example.com"));
+        assert_ok!(EndsWith(".com").check("example.com"));
+        assert_err!(EndsWith(".com").check("example.org"));
+        assert_ok!(IgnoreCase(EndsWith(".COM")).check("example.com"));
+    }
+    #[test]
+    fn contains_item() {
+        assert_ok!(ContainsItem("b").check(&vec!["a", "b"]));
+        assert_ok!(ContainsItem("b").check(&vec![String::from("b")]));
+        assert_err!(ContainsItem(3).check(&[1, 2]));
+        assert_ok!(NotContainsItem(3).check(&[1, 2]));
+        assert_err!(NotContainsItem(2).check(&[1, 2]));
+    }
+    #[test]
+    fn starts_and_ends_with_item() {
+        assert_ok!(StartsWithItem(1).check(&vec![1, 2]));
+        assert_err!(StartsWithItem(2).check(&vec![1, 2]));
+        assert_err!(StartsWithItem(1).check(&Vec::<i32>::new()));
+        assert_ok!(EndsWithItem(2).check(&vec![1, 2]));
+        assert_err!(EndsWithItem(1).check(&vec![1, 2]));
+    }
+}
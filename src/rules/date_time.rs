@@ -0,0 +1,926 @@
+use super::Rule;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an RFC 3339 date and time, such as
+/// `2022-06-01T12:30:00.5+02:00`
+///
+/// The value can be limited to a range, with bounds in the same format
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Event(#[rule(Rfc3339::new().min("2000-01-01T00:00:00Z"))] String);
+///
+/// assert!(Event(String::from("2022-06-01T12:30:00+02:00")).check_validity().is_ok());
+/// assert!(Event(String::from("1999-12-31T23:59:59Z")).check_validity().is_err());
+/// assert!(Event(String::from("2022-06-01 12:30")).check_validity().is_err());
+/// ```
+pub struct Rfc3339<'a> {
+    min: Option<&'a str>,
+    max: Option<&'a str>,
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISO 8601 complete date
+///
+/// The calendar (`2022-06-01`), ordinal (`2022-152`) and week
+/// (`2022-W22-3`) dates are accepted, in the extended or the basic
+/// format (`20220601`). The value can be limited to a range, with
+/// bounds in one of these formats.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Person(#[rule(IsoDate::new().min("1900-01-01"))] String);
+///
+/// assert!(Person(String::from("1985-04-12")).check_validity().is_ok());
+/// assert!(Person(String::from("1899-12-31")).check_validity().is_err());
+/// assert!(Person(String::from("1985-02-30")).check_validity().is_err());
+/// ```
+pub struct IsoDate<'a> {
+    min: Option<&'a str>,
+    max: Option<&'a str>,
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISO 8601 time of day
+///
+/// The hours and minutes are required, the seconds, their fraction and
+/// the UTC offset are optional, in the extended (`08:30:00+01:00`) or
+/// the basic format (`083000+0100`). The value can be limited to a range,
+/// with bounds in one of these formats.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct OpeningTime(#[rule(IsoTime::new().min("08:00").max("12:00"))] String);
+///
+/// assert!(OpeningTime(String::from("09:30")).check_validity().is_ok());
+/// assert!(OpeningTime(String::from("13:00")).check_validity().is_err());
+/// assert!(OpeningTime(String::from("9h30")).check_validity().is_err());
+/// ```
+pub struct IsoTime<'a> {
+    min: Option<&'a str>,
+    max: Option<&'a str>,
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISO 8601 duration, such as
+/// `P1Y2M10DT2H30M` or `P3W`
+///
+/// Only the last component can have a fraction. The value can be
+/// limited to a range, with bounds in the same format, if they don't
+/// contain years or months whose length varies.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Timeout(#[rule(IsoDuration::new().max("PT1H"))] String);
+///
+/// assert!(Timeout(String::from("PT30M")).check_validity().is_ok());
+/// assert!(Timeout(String::from("PT1H0.5S")).check_validity().is_err());
+/// assert!(Timeout(String::from("30M")).check_validity().is_err());
+/// ```
+pub struct IsoDuration<'a> {
+    min: Option<&'a str>,
+    max: Option<&'a str>,
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to match a `strftime` format
+///
+/// The supported specifiers are:
+///
+/// - `%Y`: year with 4 digits, `%y`: year with 2 digits (1969 to 2068)
+/// - `%m`: month with 2 digits, `%b`: abbreviated name, `%B`: full name
+/// - `%d`: day of the month with 2 digits, `%e`: with 1 or 2 digits, `%j`: day of the year with 3 digits
+/// - `%a`: abbreviated name of the weekday, `%A`: full name
+/// - `%H`: hour with 2 digits, `%I`: hour from 01 to 12, `%p`: `AM` or `PM`
+/// - `%M`: minute, `%S`: second, `%f`: fraction of second digits
+/// - `%z`: UTC offset as `+hhmm` or `+hh:mm`, `Z` is accepted
+/// - `%F`: `%Y-%m-%d`, `%T`: `%H:%M:%S`, `%%`: a `%`
+///
+/// The names are in English and the case is ignored. The value can be
+/// limited to a range, with bounds in the same format.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Birthday(#[rule(DateTimeFormat::new("%d/%m/%Y").min("01/01/1900"))] String);
+///
+/// assert!(Birthday(String::from("12/04/1985")).check_validity().is_ok());
+/// assert!(Birthday(String::from("31/12/1899")).check_validity().is_err());
+/// assert!(Birthday(String::from("1985-04-12")).check_validity().is_err());
+/// ```
+pub struct DateTimeFormat<'a> {
+    format: &'a str,
+    min: Option<&'a str>,
+    max: Option<&'a str>,
+}
+
+impl<'a> Rfc3339<'a> {
+    /// Accept any date and time
+    pub const fn new() -> Self {
+        Rfc3339 {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Only accept the values at or after `min`
+    pub const fn min(self, min: &'a str) -> Self {
+        Rfc3339 {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Only accept the values at or before `max`
+    pub const fn max(self, max: &'a str) -> Self {
+        Rfc3339 {
+            max: Some(max),
+            ..self
+        }
+    }
+}
+
+impl<'a> IsoDate<'a> {
+    /// Accept any date
+    pub const fn new() -> Self {
+        IsoDate {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Only accept the dates on or after `min`
+    pub const fn min(self, min: &'a str) -> Self {
+        IsoDate {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Only accept the dates on or before `max`
+    pub const fn max(self, max: &'a str) -> Self {
+        IsoDate {
+            max: Some(max),
+            ..self
+        }
+    }
+}
+
+impl<'a> IsoTime<'a> {
+    /// Accept any time
+    pub const fn new() -> Self {
+        IsoTime {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Only accept the times at or after `min`
+    pub const fn min(self, min: &'a str) -> Self {
+        IsoTime {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Only accept the times at or before `max`
+    pub const fn max(self, max: &'a str) -> Self {
+        IsoTime {
+            max: Some(max),
+            ..self
+        }
+    }
+}
+
+impl<'a> IsoDuration<'a> {
+    /// Accept any duration
+    pub const fn new() -> Self {
+        IsoDuration {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Only accept the durations at least as long as `min`
+    pub const fn min(self, min: &'a str) -> Self {
+        IsoDuration {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Only accept the durations at most as long as `max`
+    pub const fn max(self, max: &'a str) -> Self {
+        IsoDuration {
+            max: Some(max),
+            ..self
+        }
+    }
+}
+
+impl<'a> DateTimeFormat<'a> {
+    /// Accept any value that matches the `format`
+    pub const fn new(format: &'a str) -> Self {
+        DateTimeFormat {
+            format,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Only accept the values at or after `min`
+    pub const fn min(self, min: &'a str) -> Self {
+        DateTimeFormat {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Only accept the values at or before `max`
+    pub const fn max(self, max: &'a str) -> Self {
+        DateTimeFormat {
+            max: Some(max),
+            ..self
+        }
+    }
+}
+
+impl<'a> Default for Rfc3339<'a> {
+    fn default() -> Self {
+        Rfc3339::new()
+    }
+}
+
+impl<'a> Default for IsoDate<'a> {
+    fn default() -> Self {
+        IsoDate::new()
+    }
+}
+
+impl<'a> Default for IsoTime<'a> {
+    fn default() -> Self {
+        IsoTime::new()
+    }
+}
+
+impl<'a> Default for IsoDuration<'a> {
+    fn default() -> Self {
+        IsoDuration::new()
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for Rfc3339<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let parse = |value: &str| parse_with(value, parse_rfc3339);
+        let value = parse(value.as_ref())
+            .ok_or_else(|| String::from("The value is not an RFC 3339 date and time"))?;
+        check_bounds(&value, self.min, self.max, parse)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for IsoDate<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let parse = |value: &str| parse_with(value, parse_iso_date);
+        let value = parse(value.as_ref())
+            .ok_or_else(|| String::from("The value is not an ISO 8601 date"))?;
+        check_bounds(&value, self.min, self.max, parse)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for IsoTime<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let parse = |value: &str| parse_with(value, parse_iso_time);
+        let value = parse(value.as_ref())
+            .ok_or_else(|| String::from("The value is not an ISO 8601 time"))?;
+        check_bounds(&value, self.min, self.max, parse)
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for IsoDuration<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = Duration::parse(value.as_ref())
+            .ok_or_else(|| String::from("The value is not an ISO 8601 duration"))?;
+        if let Some(min) = self.min {
+            if value.seconds_compared_to(min)? < 0.0 {
+                return Err(String::from("The duration is too short"));
+            }
+        }
+        if let Some(max) = self.max {
+            if value.seconds_compared_to(max)? > 0.0 {
+                return Err(String::from("The duration is too long"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for DateTimeFormat<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let parse = |value: &str| parse_with(value, |cursor| parse_format(cursor, self.format));
+        let value = parse(value.as_ref())
+            .ok_or_else(|| format!("The value doesn't match the format {:?}", self.format))?;
+        check_bounds(&value, self.min, self.max, parse)
+    }
+}
+
+fn check_bounds(
+    value: &DateTime,
+    min: Option<&str>,
+    max: Option<&str>,
+    parse: impl Fn(&str) -> Option<DateTime>,
+) -> Result<(), String> {
+    let parse_bound = |bound: &str| {
+        parse(bound).ok_or_else(|| format!("The bound {:?} doesn't match the format", bound))
+    };
+    if let Some(min) = min {
+        if value.instant() < parse_bound(min)?.instant() {
+            return Err(format!("The value is before {}", min));
+        }
+    }
+    if let Some(max) = max {
+        if value.instant() > parse_bound(max)?.instant() {
+            return Err(format!("The value is after {}", max));
+        }
+    }
+    Ok(())
+}
+
+/// A parsed date and time, the missing parts are
+/// considered as 1970-01-01, midnight and UTC
+#[derive(Default)]
+struct DateTime {
+    days: i64,
+    seconds: i64,
+    nanos: u32,
+    offset: i64,
+}
+
+impl DateTime {
+    /// Seconds and nanoseconds since 1970-01-01T00:00:00Z
+    fn instant(&self) -> (i64, u32) {
+        (
+            self.days * SECONDS_PER_DAY + self.seconds - self.offset,
+            self.nanos,
+        )
+    }
+}
+
+/// Parse a whole value with `parse`
+fn parse_with(value: &str, parse: impl Fn(&mut Cursor) -> Option<DateTime>) -> Option<DateTime> {
+    let mut cursor = Cursor(value.as_bytes());
+    let date_time = parse(&mut cursor)?;
+    cursor.0.is_empty().then_some(date_time)
+}
+
+fn parse_rfc3339(cursor: &mut Cursor) -> Option<DateTime> {
+    let year = cursor.number(4, 4)?;
+    cursor.expect(b'-')?;
+    let month = cursor.number(2, 2)?;
+    cursor.expect(b'-')?;
+    let day = cursor.number(2, 2)?;
+    cursor.one_of(b"Tt ")?;
+    let hour = cursor.number(2, 2)?;
+    cursor.expect(b':')?;
+    let minute = cursor.number(2, 2)?;
+    cursor.expect(b':')?;
+    let second = cursor.number(2, 2)?;
+    let nanos = match cursor.eat(b'.') {
+        true => cursor.fraction()?,
+        false => 0,
+    };
+    let offset = match cursor.one_of(b"Zz") {
+        Some(_) => 0,
+        None => cursor.offset(true)?,
+    };
+    Some(DateTime {
+        days: days_from_date(year.into(), month, day)?,
+        seconds: seconds_from_time(hour, minute, second)?,
+        nanos,
+        offset,
+    })
+}
+
+fn parse_iso_date(cursor: &mut Cursor) -> Option<DateTime> {
+    let year = cursor.number(4, 4)?.into();
+    let extended = cursor.eat(b'-');
+    let days = if cursor.eat(b'W') {
+        let week = cursor.number(2, 2)?;
+        if extended {
+            cursor.expect(b'-')?;
+        }
+        days_from_week_date(year, week, cursor.number(1, 1)?)?
+    } else {
+        match (extended, cursor.digits_count()) {
+            (_, 3) => days_from_ordinal_date(year, cursor.number(3, 3)?)?,
+            (true, 2) => {
+                let month = cursor.number(2, 2)?;
+                cursor.expect(b'-')?;
+                days_from_date(year, month, cursor.number(2, 2)?)?
+            }
+            (false, 4) => {
+                let month = cursor.number(2, 2)?;
+                days_from_date(year, month, cursor.number(2, 2)?)?
+            }
+            _ => return None,
+        }
+    };
+    Some(DateTime {
+        days,
+        ..DateTime::default()
+    })
+}
+
+fn parse_iso_time(cursor: &mut Cursor) -> Option<DateTime> {
+    cursor.eat(b'T');
+    let hour = cursor.number(2, 2)?;
+    let extended = cursor.eat(b':');
+    let minute = cursor.number(2, 2)?;
+    let second = match (extended, cursor.eat(b':')) {
+        (true, true) => Some(cursor.number(2, 2)?),
+        (false, false) if cursor.digits_count() == 2 => Some(cursor.number(2, 2)?),
+        (false, true) => return None,
+        _ => None,
+    };
+    let nanos = match second.is_some() && cursor.one_of(b".,").is_some() {
+        true => cursor.fraction()?,
+        false => 0,
+    };
+    let offset = match cursor.eat(b'Z') {
+        true => 0,
+        false if cursor.0.is_empty() => 0,
+        false => cursor.offset(extended)?,
+    };
+    Some(DateTime {
+        seconds: seconds_from_time(hour, minute, second.unwrap_or(0))?,
+        nanos,
+        offset,
+        ..DateTime::default()
+    })
+}
+
+fn parse_format(cursor: &mut Cursor, format: &str) -> Option<DateTime> {
+    let (mut year, mut month, mut day, mut day_of_year, mut weekday) = (1970, 1, 1, None, None);
+    let (mut hour, mut minute, mut second, mut pm) = (0, 0, 0, None);
+    let mut date_time = DateTime::default();
+    let mut format = format.bytes();
+    while let Some(f) = format.next() {
+        if f != b'%' {
+            cursor.expect(f)?;
+            continue;
+        }
+        match format.next()? {
+            b'Y' => year = cursor.number(4, 4)?.into(),
+            b'y' => {
+                year = match cursor.number(2, 2)? {
+                    y @ 0..=68 => 2000 + i64::from(y),
+                    y => 1900 + i64::from(y),
+                }
+            }
+            b'm' => month = cursor.number(2, 2)?,
+            b'b' | b'h' => month = cursor.name(&MONTHS, true)? + 1,
+            b'B' => month = cursor.name(&MONTHS, false)? + 1,
+            b'd' => day = cursor.number(2, 2)?,
+            b'e' => {
+                cursor.eat(b' ');
+                day = cursor.number(1, 2)?;
+            }
+            b'j' => day_of_year = Some(cursor.number(3, 3)?),
+            b'a' => weekday = Some(cursor.name(&WEEKDAYS, true)?),
+            b'A' => weekday = Some(cursor.name(&WEEKDAYS, false)?),
+            b'H' => hour = cursor.number(2, 2)?,
+            b'I' => match cursor.number(2, 2)? {
+                h @ 1..=12 => hour = h % 12,
+                _ => return None,
+            },
+            b'p' => pm = Some(cursor.name(&["AM", "PM"], false)? == 1),
+            b'M' => minute = cursor.number(2, 2)?,
+            b'S' => second = cursor.number(2, 2)?,
+            b'f' => date_time.nanos = cursor.fraction()?,
+            b'z' => {
+                date_time.offset = match cursor.eat(b'Z') {
+                    true => 0,
+                    false => cursor.offset(cursor.0.get(3) == Some(&b':'))?,
+                }
+            }
+            b'F' => {
+                year = cursor.number(4, 4)?.into();
+                cursor.expect(b'-')?;
+                month = cursor.number(2, 2)?;
+                cursor.expect(b'-')?;
+                day = cursor.number(2, 2)?;
+            }
+            b'T' => {
+                hour = cursor.number(2, 2)?;
+                cursor.expect(b':')?;
+                minute = cursor.number(2, 2)?;
+                cursor.expect(b':')?;
+                second = cursor.number(2, 2)?;
+            }
+            b'%' => cursor.expect(b'%')?,
+            _ => return None,
+        }
+    }
+    if let Some(pm) = pm {
+        hour = hour % 12 + if pm { 12 } else { 0 };
+    }
+    date_time.days = match day_of_year {
+        Some(day_of_year) => days_from_ordinal_date(year, day_of_year)?,
+        None => days_from_date(year, month, day)?,
+    };
+    if weekday.is_some_and(|weekday| weekday != weekday_from_days(date_time.days)) {
+        return None;
+    }
+    date_time.seconds = seconds_from_time(hour, minute, second)?;
+    Some(date_time)
+}
+
+/// An ISO 8601 duration
+struct Duration {
+    years: f64,
+    months: f64,
+    seconds: f64,
+}
+
+impl Duration {
+    fn parse(value: &str) -> Option<Self> {
+        let mut cursor = Cursor(value.strip_prefix('P')?.as_bytes());
+        let mut duration = Duration {
+            years: 0.0,
+            months: 0.0,
+            seconds: 0.0,
+        };
+        let mut units: &[u8] = b"YMWD";
+        let mut components = 0;
+        let mut time = false;
+        while !cursor.0.is_empty() {
+            if !time && cursor.eat(b'T') {
+                time = true;
+                units = b"HMS";
+                if cursor.0.is_empty() {
+                    return None;
+                }
+                continue;
+            }
+            let integer = cursor.number(1, 18)?;
+            let fraction = match cursor.one_of(b".,") {
+                Some(_) => f64::from(cursor.fraction()?) / 1e9,
+                None => 0.0,
+            };
+            let unit = cursor.one_of(units)?;
+            units = &units[units.iter().position(|u| *u == unit)? + 1..];
+            let amount = integer as f64 + fraction;
+            match (time, unit) {
+                (false, b'Y') => duration.years = amount,
+                (false, b'M') => duration.months = amount,
+                (false, b'W') => duration.seconds += amount * 7.0 * SECONDS_PER_DAY as f64,
+                (false, _) => duration.seconds += amount * SECONDS_PER_DAY as f64,
+                (true, b'H') => duration.seconds += amount * 3600.0,
+                (true, b'M') => duration.seconds += amount * 60.0,
+                (true, _) => duration.seconds += amount,
+            }
+            components += 1;
+            if (fraction > 0.0 || unit == b'W') && !cursor.0.is_empty() {
+                return None;
+            }
+            if unit == b'W' && components > 1 {
+                return None;
+            }
+        }
+        (components > 0).then_some(duration)
+    }
+
+    /// Difference in seconds with the `bound`, the durations
+    /// with years or months can't be compared
+    fn seconds_compared_to(&self, bound: &str) -> Result<f64, String> {
+        let bound = Duration::parse(bound)
+            .ok_or_else(|| format!("The bound {:?} is not an ISO 8601 duration", bound))?;
+        match [self, &bound]
+            .iter()
+            .all(|duration| duration.years == 0.0 && duration.months == 0.0)
+        {
+            true => Ok(self.seconds - bound.seconds),
+            false => Err(String::from(
+                "The durations with years or months can't be compared",
+            )),
+        }
+    }
+}
+
+/// Bytes of a value that are not parsed yet
+struct Cursor<'a>(&'a [u8]);
+
+impl<'a> Cursor<'a> {
+    fn eat(&mut self, c: u8) -> bool {
+        self.one_of(&[c]).is_some()
+    }
+
+    fn expect(&mut self, c: u8) -> Option<()> {
+        self.eat(c).then_some(())
+    }
+
+    fn one_of(&mut self, chars: &[u8]) -> Option<u8> {
+        let (first, rest) = self.0.split_first()?;
+        chars.contains(first).then(|| {
+            self.0 = rest;
+            *first
+        })
+    }
+
+    fn digits_count(&self) -> usize {
+        self.0.iter().take_while(|c| c.is_ascii_digit()).count()
+    }
+
+    /// Parse a number of `min` to `max` digits
+    fn number(&mut self, min: usize, max: usize) -> Option<u32> {
+        let count = self.digits_count().min(max);
+        if count < min {
+            return None;
+        }
+        let (digits, rest) = self.0.split_at(count);
+        self.0 = rest;
+        std::str::from_utf8(digits).ok()?.parse().ok()
+    }
+
+    /// Parse the digits of a fraction of second as nanoseconds
+    fn fraction(&mut self) -> Option<u32> {
+        let count = self.digits_count();
+        if count == 0 {
+            return None;
+        }
+        let (digits, rest) = self.0.split_at(count);
+        self.0 = rest;
+        let nanos = digits
+            .iter()
+            .chain(std::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |nanos, digit| nanos * 10 + u32::from(digit - b'0'));
+        Some(nanos)
+    }
+
+    /// Parse an offset `+hh:mm` if `extended`, else `+hhmm` or `+hh`
+    fn offset(&mut self, extended: bool) -> Option<i64> {
+        let sign = match self.one_of(b"+-")? {
+            b'+' => 1,
+            _ => -1,
+        };
+        let hours = self.number(2, 2)?;
+        let minutes = match extended {
+            true => {
+                self.expect(b':')?;
+                self.number(2, 2)?
+            }
+            false if self.digits_count() > 0 => self.number(2, 2)?,
+            false => 0,
+        };
+        (hours < 24 && minutes < 60).then(|| sign * i64::from(hours * 3600 + minutes * 60))
+    }
+
+    /// Parse one of the `names` without considering the case,
+    /// or their three first letters if `abbreviated`
+    fn name(&mut self, names: &[&str], abbreviated: bool) -> Option<u32> {
+        names.iter().enumerate().find_map(|(i, name)| {
+            let name = match abbreviated {
+                true => &name[..3],
+                false => name,
+            };
+            let candidate = self.0.get(..name.len())?;
+            candidate.eq_ignore_ascii_case(name.as_bytes()).then(|| {
+                self.0 = &self.0[name.len()..];
+                i as u32
+            })
+        })
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a valid date
+pub(crate) fn days_from_date(year: i64, month: u32, day: u32) -> Option<i64> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+/// Year, month and day of a number of days since 1970-01-01
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn date_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = era * 400 + year_of_era + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn days_from_ordinal_date(year: i64, day_of_year: u32) -> Option<i64> {
+    let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+    if day_of_year == 0 || day_of_year > days_in_year {
+        return None;
+    }
+    Some(days_from_date(year, 1, 1)? + i64::from(day_of_year) - 1)
+}
+
+/// The week 1 is the week with the first Thursday of the year
+fn days_from_week_date(year: i64, week: u32, weekday: u32) -> Option<i64> {
+    let january_4 = days_from_date(year, 1, 4)?;
+    let week_1 = january_4 - i64::from(weekday_from_days(january_4));
+    let next_week_1 = days_from_date(year + 1, 1, 4)?;
+    let next_week_1 = next_week_1 - i64::from(weekday_from_days(next_week_1));
+    let weeks_in_year = (next_week_1 - week_1) / 7;
+    if week == 0 || i64::from(week) > weeks_in_year || !(1..=7).contains(&weekday) {
+        return None;
+    }
+    Some(week_1 + i64::from((week - 1) * 7 + weekday - 1))
+}
+
+/// Day of the week from 0 for Monday to 6 for Sunday
+fn weekday_from_days(days: i64) -> u32 {
+    (days + 3).rem_euclid(7) as u32
+}
+
+/// Seconds since midnight, the leap second 60 is accepted
+fn seconds_from_time(hour: u32, minute: u32, second: u32) -> Option<i64> {
+    (hour < 24 && minute < 60 && second <= 60)
+        .then(|| i64::from(hour * 3600 + minute * 60 + second))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{DateTimeFormat, IsoDate, IsoDuration, IsoTime, Rfc3339, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn rfc3339() {
+        assert_ok!(Rfc3339::new().check("1985-04-12T23:20:50.52Z"));
+        assert_ok!(Rfc3339::new().check("1996-12-19t16:39:57-08:00"));
+        assert_ok!(Rfc3339::new().check("2020-02-29 00:00:00+00:00"));
+        assert_err!(Rfc3339::new().check("2021-02-29T00:00:00Z"));
+        assert_err!(Rfc3339::new().check("1985-04-12T23:20:50"));
+        assert_err!(Rfc3339::new().check("1985-04-12T24:00:00Z"));
+        let res_error_message = Rfc3339::new()
+            .check("1985-04-12")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The value is not an RFC 3339 date and time"
+        );
+    }
+    #[test]
+    fn rfc3339_range() {
+        let rule = Rfc3339::new().max("2000-01-01T00:00:00Z");
+        assert_ok!(rule.check("2000-01-01T01:00:00+01:00"));
+        let res_error_message = rule
+            .check("2000-01-01T00:00:00-01:00")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The value is after 2000-01-01T00:00:00Z");
+    }
+    #[test]
+    fn iso_date() {
+        assert_ok!(IsoDate::new().check("2022-06-01"));
+        assert_ok!(IsoDate::new().check("20220601"));
+        assert_ok!(IsoDate::new().check("2020-366"));
+        assert_ok!(IsoDate::new().check("2020-W53-7"));
+        assert_ok!(IsoDate::new().check("2022W223"));
+        assert_err!(IsoDate::new().check("2021-366"));
+        assert_err!(IsoDate::new().check("2021-W53-1"));
+        assert_err!(IsoDate::new().check("2022-0601"));
+        assert_err!(IsoDate::new().check("2022-06"));
+    }
+    #[test]
+    fn iso_date_range() {
+        let rule = IsoDate::new().min("1900-01-01").max("2000-W01-1");
+        assert_ok!(rule.check("1999-001"));
+        assert_ok!(rule.check("2000-01-03"));
+        assert_err!(rule.check("2000-01-04"));
+        let res_error_message = rule.check("1899-12-31").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The value is before 1900-01-01");
+    }
+    #[test]
+    fn iso_time() {
+        assert_ok!(IsoTime::new().check("08:30"));
+        assert_ok!(IsoTime::new().check("T08:30:15,5"));
+        assert_ok!(IsoTime::new().check("083015.5+0100"));
+        assert_ok!(IsoTime::new().check("08:30:15Z"));
+        assert_ok!(IsoTime::new().check("08:30:15-05:00"));
+        assert_err!(IsoTime::new().check("08:30:15-0500"));
+        assert_err!(IsoTime::new().check("08:3015"));
+        assert_err!(IsoTime::new().check("08"));
+        assert_err!(IsoTime::new().check("25:00"));
+    }
+    #[test]
+    fn iso_duration() {
+        assert_ok!(IsoDuration::new().check("P1Y2M10DT2H30M"));
+        assert_ok!(IsoDuration::new().check("PT0.5S"));
+        assert_ok!(IsoDuration::new().check("P3W"));
+        assert_err!(IsoDuration::new().check("P"));
+        assert_err!(IsoDuration::new().check("PT"));
+        assert_err!(IsoDuration::new().check("P1DT"));
+        assert_err!(IsoDuration::new().check("PT1M1H"));
+        assert_err!(IsoDuration::new().check("PT1.5M1S"));
+        assert_err!(IsoDuration::new().check("P1W1D"));
+    }
+    #[test]
+    fn iso_duration_range() {
+        let rule = IsoDuration::new().min("PT1M").max("P1D");
+        assert_ok!(rule.check("PT24H"));
+        let res_error_message = rule.check("PT59S").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The duration is too short");
+        assert_err!(rule.check("P1DT1S"));
+        assert_err!(rule.check("P1M"));
+    }
+    #[test]
+    fn date_time_format() {
+        assert_ok!(DateTimeFormat::new("%d/%m/%Y %H:%M").check("01/06/2022 08:30"));
+        assert_ok!(
+            DateTimeFormat::new("%a, %e %b %Y %T %z").check("Wed, 1 Jun 2022 08:30:00 +0200")
+        );
+        assert_ok!(DateTimeFormat::new("%B %d, %y %I%p").check("june 01, 22 08PM"));
+        assert_ok!(DateTimeFormat::new("%Y-%j").check("2022-152"));
+        assert_err!(DateTimeFormat::new("%a %F").check("Thu 2022-06-01"));
+        assert_err!(DateTimeFormat::new("%d/%m/%Y").check("1/6/2022"));
+        let res_error_message = DateTimeFormat::new("%Y%%")
+            .check("2022")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The value doesn't match the format \"%Y%%\""
+        );
+    }
+    #[test]
+    fn date_time_format_range() {
+        let rule = DateTimeFormat::new("%I:%M %p")
+            .min("09:00 AM")
+            .max("05:00 PM");
+        assert_ok!(rule.check("12:00 PM"));
+        assert_err!(rule.check("12:00 AM"));
+        assert_err!(rule.check("05:01 PM"));
+    }
+}
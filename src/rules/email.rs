@@ -0,0 +1,154 @@
+use super::hostname::is_valid_hostname;
+use super::Rule;
+
+const MAX_EMAIL_LENGTH: usize = 254;
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+const MAX_DOMAIN_LENGTH: usize = 253;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an email address
+///
+/// The address is checked as the `email` input of HTML5, a pragmatic
+/// subset of RFC 5322 without comments and quoted strings, with the
+/// length limits of RFC 5321: 64 bytes for the local part, 253 bytes
+/// for the domain and 254 bytes for the whole address
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Mail(#[rule(Email())] String);
+///
+/// assert!(Mail(String::from("john.smith@example.com")).check_validity().is_ok());
+/// assert!(Mail(String::from("john.smith@example..com")).check_validity().is_err());
+/// ```
+pub struct Email();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an email address, with an internationalized
+/// domain name
+///
+/// Same as [`Email`] but the domain is converted to its ASCII (punycode)
+/// form before being checked
+///
+/// You need the `idna` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Mail(#[rule(IdnEmail())] String);
+///
+/// assert!(Mail(String::from("info@münchen.de")).check_validity().is_ok());
+/// ```
+#[cfg(feature = "idna")]
+#[cfg_attr(docsrs, doc(cfg(feature = "idna")))]
+pub struct IdnEmail();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Email {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (local_part, domain) = split_email(value.as_ref())?;
+        check_email(value.as_ref(), local_part, domain)
+    }
+}
+
+#[cfg(feature = "idna")]
+impl<T: AsRef<str> + ?Sized> Rule<T> for IdnEmail {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (local_part, domain) = split_email(value.as_ref())?;
+        let domain = idna::domain_to_ascii(domain)
+            .map_err(|_| String::from("The domain of the email is invalid"))?;
+        let email = format!("{}@{}", local_part, domain);
+        check_email(&email, local_part, &domain)
+    }
+}
+
+fn split_email(value: &str) -> Result<(&str, &str), String> {
+    value
+        .rsplit_once('@')
+        .ok_or_else(|| String::from("The email is missing an @"))
+}
+
+fn check_email(email: &str, local_part: &str, domain: &str) -> Result<(), String> {
+    if local_part.len() > MAX_LOCAL_PART_LENGTH {
+        return Err(String::from("The local part of the email is too long"));
+    }
+    if !is_valid_local_part(local_part) {
+        return Err(String::from("The local part of the email is invalid"));
+    }
+    if domain.len() > MAX_DOMAIN_LENGTH {
+        return Err(String::from("The domain of the email is too long"));
+    }
+    if domain.ends_with('.') || !is_valid_hostname(domain) {
+        return Err(String::from("The domain of the email is invalid"));
+    }
+    if email.len() > MAX_EMAIL_LENGTH {
+        return Err(String::from("The email is too long"));
+    }
+    Ok(())
+}
+
+/// Dot separated atoms of the characters allowed by HTML5
+fn is_valid_local_part(local_part: &str) -> bool {
+    local_part.split('.').all(|atom| {
+        !atom.is_empty()
+            && atom
+                .bytes()
+                .all(|c| c.is_ascii_alphanumeric() || b"!#$%&'*+/=?^_`{|}~-".contains(&c))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Email, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn email_ok() {
+        assert_ok!(Email().check("john.smith+tag@example.co.uk"));
+        assert_ok!(Email().check("o'brien@localhost"));
+    }
+    #[test]
+    fn email_missing_at() {
+        let res_error_message = Email().check("example.com").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The email is missing an @");
+    }
+    #[test]
+    fn email_invalid_local_part() {
+        assert_err!(Email().check("john..smith@example.com"));
+        assert_err!(Email().check("john smith@example.com"));
+        assert_err!(Email().check("@example.com"));
+    }
+    #[test]
+    fn email_invalid_domain() {
+        assert_err!(Email().check("john@-example.com"));
+        assert_err!(Email().check("john@exa_mple.com"));
+        assert_err!(Email().check("john@"));
+    }
+    #[test]
+    fn email_too_long() {
+        let local_part = "a".repeat(65);
+        let res_error_message = Email()
+            .check(&format!("{}@example.com", local_part))
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The local part of the email is too long");
+    }
+
+    #[cfg(feature = "idna")]
+    mod idna_tests {
+        use crate::rules::{IdnEmail, Rule};
+        use claim::{assert_err, assert_ok};
+
+        #[test]
+        fn idn_email_ok() {
+            assert_ok!(IdnEmail().check("info@münchen.de"));
+            assert_ok!(IdnEmail().check("info@example.com"));
+        }
+        #[test]
+        fn idn_email_err() {
+            assert_err!(IdnEmail().check("info@mün chen.de"));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a GTIN
+///
+/// GTIN-8, GTIN-12 (UPC-A), GTIN-13 (EAN-13) or GTIN-14 digits with
+/// a valid check digit
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Product(#[rule(Gtin())] String);
+///
+/// assert!(Product(String::from("4006381333931")).check_validity().is_ok());
+/// assert!(Product(String::from("4006381333932")).check_validity().is_err());
+/// ```
+pub struct Gtin();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an EAN-8 or an EAN-13
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Product(#[rule(Ean())] String);
+///
+/// assert!(Product(String::from("73513537")).check_validity().is_ok());
+/// ```
+pub struct Ean();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an UPC-A
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Product(#[rule(Upc())] String);
+///
+/// assert!(Product(String::from("036000291452")).check_validity().is_ok());
+/// ```
+pub struct Upc();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Gtin {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_gtin(value.as_ref(), &[8, 12, 13, 14], "GTIN")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Ean {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_gtin(value.as_ref(), &[8, 13], "EAN")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Upc {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_gtin(value.as_ref(), &[12], "UPC")
+    }
+}
+
+fn check_gtin(value: &str, lengths: &[usize], name: &str) -> Result<(), String> {
+    if !lengths.contains(&value.len()) || !value.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(format!("The {} format is invalid", name));
+    }
+    match gtin_checksum(value) {
+        true => Ok(()),
+        false => Err(format!("The {} check digit is invalid", name)),
+    }
+}
+
+/// From the right, the digits are weighted by 1 and 3 alternately
+pub(crate) fn gtin_checksum(digits: &str) -> bool {
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .map(|c| u32::from(c - b'0'))
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            0 => digit,
+            _ => digit * 3,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Ean, Gtin, Rule, Upc};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn gtin_ok() {
+        assert_ok!(Gtin().check("73513537"));
+        assert_ok!(Gtin().check("036000291452"));
+        assert_ok!(Gtin().check("10614141000415"));
+    }
+    #[test]
+    fn gtin_format_err() {
+        let res_error_message = Gtin().check("4006381333").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The GTIN format is invalid");
+    }
+    #[test]
+    fn gtin_check_digit_err() {
+        let res_error_message = Gtin().check("036000291453").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The GTIN check digit is invalid");
+    }
+    #[test]
+    fn ean_err() {
+        assert_err!(Ean().check("036000291452"));
+    }
+    #[test]
+    fn upc_err() {
+        assert_err!(Upc().check("4006381333931"));
+    }
+}
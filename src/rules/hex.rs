@@ -0,0 +1,119 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be hexadecimal digits, in lowercase
+/// or uppercase
+///
+/// It can require an even length, so that the digits can be decoded
+/// as bytes, or an exact number of decoded bytes
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Sha256(#[rule(Hex::new().bytes(32))] String);
+///
+/// #[derive(Validator)]
+/// struct Payload(#[rule(Hex::new().even_length())] String);
+///
+/// let digest = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+/// assert!(Sha256(String::from(digest)).check_validity().is_ok());
+/// assert!(Payload(String::from("C0FFEE")).check_validity().is_ok());
+/// assert!(Payload(String::from("C0FFE")).check_validity().is_err());
+/// ```
+pub struct Hex {
+    even_length: bool,
+    bytes: Option<usize>,
+}
+
+impl Hex {
+    /// Accept any number of hexadecimal digits
+    pub const fn new() -> Self {
+        Hex {
+            even_length: false,
+            bytes: None,
+        }
+    }
+
+    /// Only accept an even number of digits
+    pub const fn even_length(self) -> Self {
+        Hex {
+            even_length: true,
+            ..self
+        }
+    }
+
+    /// Only accept the values that decode to exactly `bytes` bytes
+    pub const fn bytes(self, bytes: usize) -> Self {
+        Hex {
+            bytes: Some(bytes),
+            ..self
+        }
+    }
+}
+
+impl Default for Hex {
+    fn default() -> Self {
+        Hex::new()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Hex {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        if let Some((position, c)) = value
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_hexdigit())
+        {
+            return Err(format!(
+                "The character {:?} at position {} is not a hexadecimal digit",
+                c, position
+            ));
+        }
+        if self.even_length && value.len() % 2 != 0 {
+            return Err(String::from("The hexadecimal value has an odd length"));
+        }
+        match self.bytes {
+            Some(bytes) if value.len() != bytes * 2 => Err(format!(
+                "The hexadecimal value must contain {} bytes",
+                bytes
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Hex, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn hex_ok() {
+        assert_ok!(Hex::new().check("0123456789abcdefABCDEF"));
+        assert_ok!(Hex::new().check("abc"));
+    }
+    #[test]
+    fn hex_digit_err() {
+        let res_error_message = Hex::new().check("0x12").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The character 'x' at position 1 is not a hexadecimal digit"
+        );
+    }
+    #[test]
+    fn hex_length() {
+        assert_err!(Hex::new().even_length().check("abc"));
+        assert_ok!(Hex::new().bytes(2).check("abcd"));
+        let res_error_message = Hex::new()
+            .bytes(2)
+            .check("abcdef")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The hexadecimal value must contain 2 bytes"
+        );
+    }
+}
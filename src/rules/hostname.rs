@@ -0,0 +1,125 @@
+use super::Rule;
+use std::net::Ipv4Addr;
+
+const MAX_HOSTNAME_LENGTH: usize = 253;
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a hostname as defined by RFC 1123
+///
+/// Dot separated labels of 1 to 63 letters, digits and hyphens that
+/// don't start or end with a hyphen, for a total of 253 characters at
+/// most. A trailing dot is accepted. Dot separated numbers such as
+/// `999.999.999.999` are rejected unless they are an IPv4 address.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Host(#[rule(Hostname())] String);
+///
+/// assert!(Host(String::from("db-1.internal")).check_validity().is_ok());
+/// assert!(Host(String::from("db_1.internal")).check_validity().is_err());
+/// ```
+pub struct Hostname();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a domain name
+///
+/// A [`Hostname`] with at least two labels whose top level domain
+/// isn't numeric
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Domain(#[rule(DomainName())] String);
+///
+/// assert!(Domain(String::from("example.com")).check_validity().is_ok());
+/// assert!(Domain(String::from("localhost")).check_validity().is_err());
+/// ```
+pub struct DomainName();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Hostname {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match is_valid_hostname(value.as_ref()) {
+            true => Ok(()),
+            false => Err(String::from("The value is not a valid hostname")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for DomainName {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let top_level_domain = value.strip_suffix('.').unwrap_or(value).rsplit('.').next();
+        match top_level_domain {
+            Some(tld)
+                if is_valid_hostname(value)
+                    && tld.len() < value.len()
+                    && !tld.bytes().all(|c| c.is_ascii_digit()) =>
+            {
+                Ok(())
+            }
+            _ => Err(String::from("The value is not a valid domain name")),
+        }
+    }
+}
+
+/// Check the hostname syntax of RFC 1123
+///
+/// The dotted-decimal values must be an IPv4 address, so a value such
+/// as `999.999.999.999` is not a hostname
+pub(crate) fn is_valid_hostname(hostname: &str) -> bool {
+    let hostname = hostname.strip_suffix('.').unwrap_or(hostname);
+    let is_dotted_decimal =
+        hostname.contains('.') && hostname.bytes().all(|c| c.is_ascii_digit() || c == b'.');
+    if is_dotted_decimal {
+        return hostname.parse::<Ipv4Addr>().is_ok();
+    }
+    hostname.len() <= MAX_HOSTNAME_LENGTH
+        && hostname.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= MAX_LABEL_LENGTH
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{DomainName, Hostname, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn hostname_ok() {
+        assert_ok!(Hostname().check("localhost"));
+        assert_ok!(Hostname().check("3com.example.com."));
+    }
+    #[test]
+    fn hostname_err() {
+        assert_err!(Hostname().check(""));
+        assert_err!(Hostname().check("example..com"));
+        assert_err!(Hostname().check("-example.com"));
+        assert_err!(Hostname().check(&"a".repeat(64)));
+        assert_err!(Hostname().check("999.999.999.999"));
+        assert_err!(Hostname().check("1.2.3"));
+        assert_err!(Hostname().check("1.2.3.4.5"));
+    }
+    #[test]
+    fn domain_name_ok() {
+        assert_ok!(DomainName().check("example.co.uk"));
+        assert_ok!(DomainName().check("xn--mnchen-3ya.de"));
+    }
+    #[test]
+    fn domain_name_err() {
+        assert_err!(DomainName().check("localhost"));
+        assert_err!(DomainName().check("192.168.1.1"));
+        assert_err!(DomainName().check("."));
+    }
+}
@@ -0,0 +1,186 @@
+use super::Rule;
+
+/// Length of the IBAN of each country of the IBAN registry
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HN", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+    ("YE", 30),
+];
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an IBAN
+///
+/// The IBAN is in uppercase, optionally with spaces such as in its
+/// print format. Its length must match its country and its mod-97
+/// checksum must be valid.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct BankAccount(#[rule(Iban())] String);
+///
+/// assert!(BankAccount(String::from("FR76 3000 6000 0112 3456 7890 189")).check_validity().is_ok());
+/// assert!(BankAccount(String::from("FR76 3000 6000 0112 3456 7890 188")).check_validity().is_err());
+/// ```
+pub struct Iban();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Iban {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let iban: Vec<u8> = value.as_ref().bytes().filter(|c| *c != b' ').collect();
+        let valid_format = iban.len() > 4
+            && iban[..2].iter().all(u8::is_ascii_uppercase)
+            && iban[2..4].iter().all(u8::is_ascii_digit)
+            && iban[4..]
+                .iter()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase());
+        if !valid_format {
+            return Err(String::from("The IBAN format is invalid"));
+        }
+        let expected_length = IBAN_LENGTHS
+            .iter()
+            .find(|(country, _)| country.as_bytes() == &iban[..2])
+            .map(|(_, length)| *length);
+        if expected_length != Some(iban.len()) {
+            return Err(String::from("The IBAN length doesn't match its country"));
+        }
+        match mod_97(iban[4..].iter().chain(&iban[..4])) {
+            1 => Ok(()),
+            _ => Err(String::from("The IBAN checksum is invalid")),
+        }
+    }
+}
+
+/// Remainder of the division by 97 of the number made by replacing
+/// the letters by 10 to 35
+fn mod_97<'a>(chars: impl Iterator<Item = &'a u8>) -> u32 {
+    chars.fold(0, |remainder, c| match c {
+        b'0'..=b'9' => (remainder * 10 + u32::from(c - b'0')) % 97,
+        _ => (remainder * 100 + u32::from(c - b'A') + 10) % 97,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Iban, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn iban_ok() {
+        assert_ok!(Iban().check("GB82WEST12345698765432"));
+        assert_ok!(Iban().check("DE89 3704 0044 0532 0130 00"));
+    }
+    #[test]
+    fn iban_format_err() {
+        let res_error_message = Iban()
+            .check("gb82west12345698765432")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The IBAN format is invalid");
+        assert_err!(Iban().check("GB82-WEST-1234-5698-7654-32"));
+    }
+    #[test]
+    fn iban_length_err() {
+        let res_error_message = Iban()
+            .check("GB82WEST1234569876543")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The IBAN length doesn't match its country"
+        );
+        assert_err!(Iban().check("ZZ82WEST12345698765432"));
+    }
+    #[test]
+    fn iban_checksum_err() {
+        let res_error_message = Iban()
+            .check("GB83WEST12345698765432")
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The IBAN checksum is invalid");
+    }
+}
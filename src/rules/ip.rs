@@ -0,0 +1,171 @@
+use super::Rule;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an IPv4 or IPv6 address
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Server(#[rule(Ip())] String);
+///
+/// assert!(Server(String::from("192.168.1.1")).check_validity().is_ok());
+/// assert!(Server(String::from("::1")).check_validity().is_ok());
+/// ```
+pub struct Ip();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an IPv4 address in dotted decimal notation
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Server(#[rule(Ipv4())] String);
+///
+/// assert!(Server(String::from("192.168.1.1")).check_validity().is_ok());
+/// assert!(Server(String::from("192.168.1.256")).check_validity().is_err());
+/// ```
+pub struct Ipv4();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an IPv6 address
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Server(#[rule(Ipv6())] String);
+///
+/// assert!(Server(String::from("2001:db8::1")).check_validity().is_ok());
+/// assert!(Server(String::from("192.168.1.1")).check_validity().is_err());
+/// ```
+pub struct Ipv6();
+
+/// Rule to constraint an [`IpAddr`] or a [`SocketAddr`] to be IPv4
+///
+/// # Example
+/// ```
+/// use std::net::IpAddr;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Server(#[rule(Ipv4Only())] IpAddr);
+///
+/// assert!(Server("192.168.1.1".parse().unwrap()).check_validity().is_ok());
+/// assert!(Server("::1".parse().unwrap()).check_validity().is_err());
+/// ```
+pub struct Ipv4Only();
+
+/// Rule to constraint an [`IpAddr`] or a [`SocketAddr`] to be IPv6
+///
+/// # Example
+/// ```
+/// use std::net::SocketAddr;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Server(#[rule(Ipv6Only())] SocketAddr);
+///
+/// assert!(Server("[::1]:8080".parse().unwrap()).check_validity().is_ok());
+/// ```
+pub struct Ipv6Only();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Ip {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match value.as_ref().parse::<IpAddr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(String::from("The value is not an IP address")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Ipv4 {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match value.as_ref().parse::<Ipv4Addr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(String::from("The value is not an IPv4 address")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Ipv6 {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match value.as_ref().parse::<Ipv6Addr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(String::from("The value is not an IPv6 address")),
+        }
+    }
+}
+
+impl Rule<IpAddr> for Ipv4Only {
+    fn check(&self, value: &IpAddr) -> Result<(), String> {
+        match value.is_ipv4() {
+            true => Ok(()),
+            false => Err(String::from("The IP address is not IPv4")),
+        }
+    }
+}
+
+impl Rule<SocketAddr> for Ipv4Only {
+    fn check(&self, value: &SocketAddr) -> Result<(), String> {
+        self.check(&value.ip())
+    }
+}
+
+impl Rule<IpAddr> for Ipv6Only {
+    fn check(&self, value: &IpAddr) -> Result<(), String> {
+        match value.is_ipv6() {
+            true => Ok(()),
+            false => Err(String::from("The IP address is not IPv6")),
+        }
+    }
+}
+
+impl Rule<SocketAddr> for Ipv6Only {
+    fn check(&self, value: &SocketAddr) -> Result<(), String> {
+        self.check(&value.ip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Ip, Ipv4, Ipv4Only, Ipv6, Ipv6Only, Rule};
+    use claim::{assert_err, assert_ok};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn ip_ok() {
+        assert_ok!(Ip().check("10.0.0.1"));
+        assert_ok!(Ip().check("fe80::1"));
+    }
+    #[test]
+    fn ip_err() {
+        assert_err!(Ip().check("example.com"));
+    }
+    #[test]
+    fn ipv4_err() {
+        assert_err!(Ipv4().check("::1"));
+        assert_err!(Ipv4().check("10.0.0"));
+        assert_err!(Ipv4().check("010.0.0.1"));
+    }
+    #[test]
+    fn ipv6_err() {
+        assert_err!(Ipv6().check("10.0.0.1"));
+        assert_err!(Ipv6().check("2001:db8:::1"));
+    }
+    #[test]
+    fn ipv4_only() {
+        assert_ok!(Ipv4Only().check(&IpAddr::V4(Ipv4Addr::LOCALHOST)));
+        assert_err!(Ipv4Only().check(&IpAddr::V6(Ipv6Addr::LOCALHOST)));
+    }
+    #[test]
+    fn ipv6_only() {
+        assert_ok!(Ipv6Only().check(&IpAddr::V6(Ipv6Addr::LOCALHOST)));
+        assert_err!(Ipv6Only().check(&IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    }
+}
@@ -0,0 +1,194 @@
+use super::gtin::gtin_checksum;
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISBN-10 or an ISBN-13
+///
+/// The digits can be separated by hyphens or spaces
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Book(#[rule(Isbn())] String);
+///
+/// assert!(Book(String::from("978-2-07-036822-8")).check_validity().is_ok());
+/// assert!(Book(String::from("2-07-036822-5")).check_validity().is_err());
+/// ```
+pub struct Isbn();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISBN-10
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Book(#[rule(Isbn10())] String);
+///
+/// assert!(Book(String::from("0-8044-2957-X")).check_validity().is_ok());
+/// ```
+pub struct Isbn10();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISBN-13
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Book(#[rule(Isbn13())] String);
+///
+/// assert!(Book(String::from("9780306406157")).check_validity().is_ok());
+/// ```
+pub struct Isbn13();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISSN such as `0378-5955`
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Journal(#[rule(Issn())] String);
+///
+/// assert!(Journal(String::from("2049-3630")).check_validity().is_ok());
+/// assert!(Journal(String::from("2049-3631")).check_validity().is_err());
+/// ```
+pub struct Issn();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Isbn {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let isbn = isbn_chars(value.as_ref())?;
+        match isbn.len() {
+            10 => check_isbn10(&isbn),
+            13 => check_isbn13(&isbn),
+            _ => Err(String::from("The ISBN format is invalid")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Isbn10 {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_isbn10(&isbn_chars(value.as_ref())?)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Isbn13 {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_isbn13(&isbn_chars(value.as_ref())?)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Issn {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let issn: String = match value.len() {
+            9 if value.as_bytes()[4] == b'-' => value.replacen('-', "", 1),
+            _ => value.to_string(),
+        };
+        if !is_mod_11_format(&issn, 8) {
+            return Err(String::from("The ISSN format is invalid"));
+        }
+        match mod_11_checksum(&issn) {
+            true => Ok(()),
+            false => Err(String::from("The ISSN check digit is invalid")),
+        }
+    }
+}
+
+/// Remove the separators of an ISBN
+fn isbn_chars(value: &str) -> Result<String, String> {
+    let separators = |c: char| c == '-' || c == ' ';
+    if value.starts_with(separators) || value.ends_with(separators) {
+        return Err(String::from("The ISBN format is invalid"));
+    }
+    Ok(value.chars().filter(|c| !separators(*c)).collect())
+}
+
+fn check_isbn10(isbn: &str) -> Result<(), String> {
+    if !is_mod_11_format(isbn, 10) {
+        return Err(String::from("The ISBN format is invalid"));
+    }
+    match mod_11_checksum(isbn) {
+        true => Ok(()),
+        false => Err(String::from("The ISBN check digit is invalid")),
+    }
+}
+
+fn check_isbn13(isbn: &str) -> Result<(), String> {
+    let valid_format = isbn.len() == 13
+        && (isbn.starts_with("978") || isbn.starts_with("979"))
+        && isbn.bytes().all(|c| c.is_ascii_digit());
+    if !valid_format {
+        return Err(String::from("The ISBN format is invalid"));
+    }
+    match gtin_checksum(isbn) {
+        true => Ok(()),
+        false => Err(String::from("The ISBN check digit is invalid")),
+    }
+}
+
+/// Digits with a last check digit that can be `X`
+fn is_mod_11_format(value: &str, length: usize) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == length
+        && bytes[..length - 1].iter().all(u8::is_ascii_digit)
+        && (bytes[length - 1].is_ascii_digit() || bytes[length - 1] == b'X')
+}
+
+/// The digits are weighted from the length to 1, `X` is 10
+fn mod_11_checksum(value: &str) -> bool {
+    let length = value.len() as u32;
+    let sum: u32 = value
+        .bytes()
+        .enumerate()
+        .map(|(i, c)| {
+            let digit = match c {
+                b'X' => 10,
+                _ => u32::from(c - b'0'),
+            };
+            digit * (length - i as u32)
+        })
+        .sum();
+    sum % 11 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Isbn, Isbn10, Isbn13, Issn, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn isbn_ok() {
+        assert_ok!(Isbn().check("0-306-40615-2"));
+        assert_ok!(Isbn().check("978 0 306 40615 7"));
+    }
+    #[test]
+    fn isbn_format_err() {
+        let res_error_message = Isbn().check("0-306-40615").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The ISBN format is invalid");
+        assert_err!(Isbn13().check("9770306406157"));
+        assert_err!(Isbn10().check("-0306406152"));
+    }
+    #[test]
+    fn isbn_check_digit_err() {
+        let res_error_message = Isbn().check("0-306-40615-3").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The ISBN check digit is invalid");
+        assert_err!(Isbn13().check("9780306406158"));
+    }
+    #[test]
+    fn issn_ok() {
+        assert_ok!(Issn().check("2434-561X"));
+        assert_ok!(Issn().check("03785955"));
+    }
+    #[test]
+    fn issn_err() {
+        assert_err!(Issn().check("0378-595"));
+        assert_err!(Issn().check("0378-5956"));
+    }
+}
@@ -0,0 +1,400 @@
+use super::Rule;
+
+const COUNTRIES_ALPHA2: &str = include_str!("data/countries_alpha2.txt");
+const COUNTRIES_ALPHA3: &str = include_str!("data/countries_alpha3.txt");
+const CURRENCIES: &str = include_str!("data/currencies.txt");
+const LANGUAGES_ALPHA2: &str = include_str!("data/languages_alpha2.txt");
+const LANGUAGES_ALPHA3: &str = include_str!("data/languages_alpha3.txt");
+const SCRIPTS: &str = include_str!("data/scripts.txt");
+
+/// Tags of RFC 5646 that don't follow its syntax, in lowercase
+const GRANDFATHERED_TAGS: &[&str] = &[
+    "art-lojban",
+    "cel-gaulish",
+    "en-gb-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "no-bok",
+    "no-nyn",
+    "sgn-be-fr",
+    "sgn-be-nl",
+    "sgn-ch-de",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISO 3166-1 alpha-2 country code
+/// in uppercase, such as `FR`
+///
+/// You need the `iso` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Country(#[rule(CountryCode())] String);
+///
+/// assert!(Country(String::from("FR")).check_validity().is_ok());
+/// assert!(Country(String::from("FX")).check_validity().is_err());
+/// ```
+pub struct CountryCode();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISO 3166-1 alpha-3 country code
+/// in uppercase, such as `FRA`
+///
+/// You need the `iso` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Country(#[rule(CountryCodeAlpha3())] String);
+///
+/// assert!(Country(String::from("FRA")).check_validity().is_ok());
+/// assert!(Country(String::from("fra")).check_validity().is_err());
+/// ```
+pub struct CountryCodeAlpha3();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISO 4217 currency code in
+/// uppercase, such as `EUR`
+///
+/// You need the `iso` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Price {
+///     amount: u64,
+///     #[rule(CurrencyCode())]
+///     currency: String,
+/// }
+///
+/// assert!(Price { amount: 100, currency: String::from("EUR") }.check_validity().is_ok());
+/// assert!(Price { amount: 100, currency: String::from("EUD") }.check_validity().is_err());
+/// ```
+pub struct CurrencyCode();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ISO 639 language code in lowercase
+///
+/// The two letters codes of ISO 639-1 such as `fr` and the three letters
+/// codes of ISO 639-2 and ISO 639-3 such as `fra`, `fre` or `nds` are accepted
+///
+/// You need the `iso` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Language(#[rule(LanguageCode())] String);
+///
+/// assert!(Language(String::from("fr")).check_validity().is_ok());
+/// assert!(Language(String::from("fra")).check_validity().is_ok());
+/// assert!(Language(String::from("fr-FR")).check_validity().is_err());
+/// ```
+pub struct LanguageCode();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a BCP 47 language tag, such as `fr-CA`
+/// or `zh-Hant-TW`
+///
+/// The tag must follow the syntax of RFC 5646, without considering the
+/// case, its language must be an ISO 639 code, its script an ISO 15924
+/// code and its region an ISO 3166-1 alpha-2 code or an UN M.49 code.
+/// The variants and the extensions are only checked syntactically.
+///
+/// You need the `iso` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Locale(#[rule(LanguageTag())] String);
+///
+/// assert!(Locale(String::from("en-US")).check_validity().is_ok());
+/// assert!(Locale(String::from("sr-Latn-RS")).check_validity().is_ok());
+/// assert!(Locale(String::from("en_US")).check_validity().is_err());
+/// assert!(Locale(String::from("xx-US")).check_validity().is_err());
+/// ```
+pub struct LanguageTag();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for CountryCode {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match contains_code(COUNTRIES_ALPHA2, value.as_ref()) {
+            true => Ok(()),
+            false => Err(String::from(
+                "The value is not an ISO 3166-1 alpha-2 country code",
+            )),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for CountryCodeAlpha3 {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match contains_code(COUNTRIES_ALPHA3, value.as_ref()) {
+            true => Ok(()),
+            false => Err(String::from(
+                "The value is not an ISO 3166-1 alpha-3 country code",
+            )),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for CurrencyCode {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match contains_code(CURRENCIES, value.as_ref()) {
+            true => Ok(()),
+            false => Err(String::from("The value is not an ISO 4217 currency code")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for LanguageCode {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match is_language_code(value.as_ref()) {
+            true => Ok(()),
+            false => Err(String::from("The value is not an ISO 639 language code")),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for LanguageTag {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let tag = value.as_ref().to_ascii_lowercase();
+        if GRANDFATHERED_TAGS.contains(&tag.as_str()) {
+            return Ok(());
+        }
+        check_language_tag(&tag)
+    }
+}
+
+fn is_language_code(code: &str) -> bool {
+    contains_code(LANGUAGES_ALPHA2, code) || contains_code(LANGUAGES_ALPHA3, code)
+}
+
+/// Check a lowercase language tag that is not grandfathered
+fn check_language_tag(tag: &str) -> Result<(), String> {
+    let invalid = || String::from("The value is not a BCP 47 language tag");
+    let is_alpha = |subtag: &str, min: usize, max: usize| {
+        (min..=max).contains(&subtag.len()) && subtag.bytes().all(|c| c.is_ascii_lowercase())
+    };
+    let is_alphanumeric = |subtag: &str, min: usize, max: usize| {
+        (min..=max).contains(&subtag.len())
+            && subtag
+                .bytes()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    };
+    let mut subtags = tag.split('-').peekable();
+
+    if subtags.peek() != Some(&"x") {
+        let language = subtags.next().ok_or_else(invalid)?;
+        if !is_alpha(language, 2, 3) {
+            return Err(invalid());
+        }
+        if !is_language_code(language) {
+            return Err(format!(
+                "The language {:?} is not an ISO 639 code",
+                language
+            ));
+        }
+        for _ in 0..3 {
+            match subtags.peek() {
+                Some(extlang) if is_alpha(extlang, 3, 3) => {
+                    if !contains_code(LANGUAGES_ALPHA3, extlang) {
+                        return Err(format!("The language {:?} is not an ISO 639 code", extlang));
+                    }
+                    subtags.next();
+                }
+                _ => break,
+            }
+        }
+        if let Some(script) = subtags.next_if(|script| is_alpha(script, 4, 4)) {
+            let script = format!("{}{}", script[..1].to_ascii_uppercase(), &script[1..]);
+            if !contains_code(SCRIPTS, &script) {
+                return Err(format!("The script {:?} is not an ISO 15924 code", script));
+            }
+        }
+        if let Some(region) = subtags.next_if(|region| {
+            is_alpha(region, 2, 2)
+                || (region.len() == 3 && region.bytes().all(|c| c.is_ascii_digit()))
+        }) {
+            let region = region.to_ascii_uppercase();
+            if region.len() == 2 && !contains_code(COUNTRIES_ALPHA2, &region) {
+                return Err(format!("The region {:?} is not an ISO 3166-1 code", region));
+            }
+        }
+        let mut variants = Vec::new();
+        while let Some(variant) = subtags.next_if(|variant| {
+            is_alphanumeric(variant, 5, 8)
+                || (variant.len() == 4 && variant.starts_with(|c: char| c.is_ascii_digit()))
+        }) {
+            if variants.contains(&variant) {
+                return Err(invalid());
+            }
+            variants.push(variant);
+        }
+        let mut singletons = Vec::new();
+        while let Some(singleton) =
+            subtags.next_if(|singleton| is_alphanumeric(singleton, 1, 1) && *singleton != "x")
+        {
+            if singletons.contains(&singleton) {
+                return Err(invalid());
+            }
+            singletons.push(singleton);
+            let mut extension_length = 0;
+            while subtags
+                .next_if(|subtag| is_alphanumeric(subtag, 2, 8))
+                .is_some()
+            {
+                extension_length += 1;
+            }
+            if extension_length == 0 {
+                return Err(invalid());
+            }
+        }
+    }
+    if subtags.next_if_eq(&"x").is_some() {
+        let mut private_use_length = 0;
+        while subtags
+            .next_if(|subtag| is_alphanumeric(subtag, 1, 8))
+            .is_some()
+        {
+            private_use_length += 1;
+        }
+        if private_use_length == 0 {
+            return Err(invalid());
+        }
+    }
+    match subtags.next() {
+        Some(_) => Err(invalid()),
+        None => Ok(()),
+    }
+}
+
+/// Binary search in a sorted table of codes of the same length, one per line
+///
+/// The code must be a run of ASCII letters of the length of the codes of the
+/// table, so a value that spans several lines of the table can't be found
+fn contains_code(table: &str, code: &str) -> bool {
+    let code_length = match table.find('\n') {
+        Some(code_length) => code_length,
+        None => return false,
+    };
+    if code.len() != code_length || !code.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+        return false;
+    }
+    let line_length = code_length + 1;
+    let codes = table.len() / line_length;
+    let code_at = |i: usize| &table[i * line_length..i * line_length + code_length];
+    let (mut low, mut high) = (0, codes);
+    while low < high {
+        let middle = (low + high) / 2;
+        match code_at(middle).cmp(code) {
+            std::cmp::Ordering::Equal => return true,
+            std::cmp::Ordering::Less => low = middle + 1,
+            std::cmp::Ordering::Greater => high = middle,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{
+        CountryCode, CountryCodeAlpha3, CurrencyCode, LanguageCode, LanguageTag, Rule,
+    };
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn country_code() {
+        assert_ok!(CountryCode().check("AD"));
+        assert_ok!(CountryCode().check("ZW"));
+        assert_err!(CountryCode().check("fr"));
+        assert_err!(CountryCode().check("FRA"));
+        assert_err!(CountryCode().check(""));
+        assert_err!(CountryCode().check("AD\nAE\nAF"));
+        assert_err!(CountryCode().check("AD\n"));
+        assert_err!(CountryCode().check("A"));
+        assert_ok!(CountryCodeAlpha3().check("DEU"));
+        let res_error_message = CountryCodeAlpha3()
+            .check("DE")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The value is not an ISO 3166-1 alpha-3 country code"
+        );
+    }
+    #[test]
+    fn currency_code() {
+        assert_ok!(CurrencyCode().check("EUR"));
+        assert_ok!(CurrencyCode().check("JPY"));
+        assert_err!(CurrencyCode().check("ABC"));
+        assert_err!(CurrencyCode().check("AED\nAFN"));
+        assert_err!(CurrencyCode().check("EU"));
+    }
+    #[test]
+    fn language_code() {
+        assert_ok!(LanguageCode().check("en"));
+        assert_ok!(LanguageCode().check("ger"));
+        assert_ok!(LanguageCode().check("deu"));
+        assert_ok!(LanguageCode().check("yue"));
+        assert_err!(LanguageCode().check("EN"));
+        assert_err!(LanguageCode().check("zz"));
+        assert_err!(LanguageCode().check("aa\nab"));
+        assert_err!(LanguageCode().check("engl"));
+    }
+    #[test]
+    fn language_tag_ok() {
+        assert_ok!(LanguageTag().check("fr"));
+        assert_ok!(LanguageTag().check("zh-yue-HK"));
+        assert_ok!(LanguageTag().check("zh-Hant-TW"));
+        assert_ok!(LanguageTag().check("es-419"));
+        assert_ok!(LanguageTag().check("de-CH-1996"));
+        assert_ok!(LanguageTag().check("en-US-u-ca-gregory-x-private"));
+        assert_ok!(LanguageTag().check("x-whatever"));
+        assert_ok!(LanguageTag().check("i-klingon"));
+    }
+    #[test]
+    fn language_tag_err() {
+        assert_err!(LanguageTag().check("en_US"));
+        assert_err!(LanguageTag().check("en-"));
+        assert_err!(LanguageTag().check("en-US-u"));
+        assert_err!(LanguageTag().check("de-1996-1996"));
+        assert_err!(LanguageTag().check("en-a-bbb-a-ccc"));
+        let res_error_message = LanguageTag()
+            .check("en-Xyzw")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The script \"Xyzw\" is not an ISO 15924 code"
+        );
+        let res_error_message = LanguageTag().check("fr-XY").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The region \"XY\" is not an ISO 3166-1 code"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a well-formed JSON text
+///
+/// You need the `json` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Settings(#[rule(Json())] String);
+///
+/// assert!(Settings(String::from(r#"{"theme": "dark"}"#)).check_validity().is_ok());
+/// assert!(Settings(String::from(r#"{"theme": dark}"#)).check_validity().is_err());
+/// ```
+pub struct Json();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Json {
+    fn check(&self, value: &T) -> Result<(), String> {
+        serde_json::from_str::<serde_json::Value>(value.as_ref())
+            .map(|_| ())
+            .map_err(|err| format!("The value is not valid JSON: {}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Json, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn json_ok() {
+        assert_ok!(Json().check("[1, 2.5, null, true]"));
+        assert_ok!(Json().check(" \"text\" "));
+    }
+    #[test]
+    fn json_err() {
+        assert_err!(Json().check(""));
+        assert_err!(Json().check("[1, 2,]"));
+        assert_err!(Json().check("{} {}"));
+        let res_error_message = Json().check("{'a': 1}").expect_err("Should be an Err");
+        assert!(res_error_message.starts_with("The value is not valid JSON: "));
+    }
+}
@@ -0,0 +1,57 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a MAC address
+///
+/// Six groups of two hexadecimal digits separated by `:` or `-`,
+/// the same separator must be used everywhere
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Device(#[rule(MacAddress())] String);
+///
+/// assert!(Device(String::from("00:1A:2b:3c:4D:5e")).check_validity().is_ok());
+/// assert!(Device(String::from("00:1A-2b:3c:4D:5e")).check_validity().is_err());
+/// ```
+pub struct MacAddress();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for MacAddress {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let separator = value.chars().nth(2).filter(|c| *c == ':' || *c == '-');
+        let valid = match separator {
+            Some(separator) => {
+                let groups: Vec<&str> = value.split(separator).collect();
+                groups.len() == 6
+                    && groups.iter().all(|group| {
+                        group.len() == 2 && group.bytes().all(|c| c.is_ascii_hexdigit())
+                    })
+            }
+            None => false,
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(String::from("The value is not a MAC address")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{MacAddress, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn mac_address_ok() {
+        assert_ok!(MacAddress().check("00-1a-2b-3c-4d-5e"));
+    }
+    #[test]
+    fn mac_address_err() {
+        assert_err!(MacAddress().check("00:1a:2b:3c:4d"));
+        assert_err!(MacAddress().check("00:1a:2b:3c:4d:5g"));
+        assert_err!(MacAddress().check("001a.2b3c.4d5e"));
+    }
+}
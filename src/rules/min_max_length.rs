@@ -4,6 +4,9 @@ use super::Rule;
 /// length of any type that implements [`AsRef<str>`] such
 /// as [`String`] or `&str`
 ///
+/// The length is the number of bytes, use [`MinMaxLengthOf`]
+/// to count the characters, graphemes or display width
+///
 /// # Example
 /// ```
 /// use type_rules::prelude::*;
@@ -22,6 +25,9 @@ pub struct MinMaxLength(pub usize, pub usize);
 /// length of any type that implements [`AsRef<str>`] such
 /// as [`String`] or `&str`
 ///
+/// The length is the number of bytes, use [`MinLengthOf`]
+/// to count the characters, graphemes or display width
+///
 /// # Example
 /// ```
 /// use type_rules::prelude::*;
@@ -35,6 +41,9 @@ pub struct MinLength(pub usize);
 /// length of any type that implements [`AsRef<str>`] such
 /// as [`String`] or `&str`
 ///
+/// The length is the number of bytes, use [`MaxLengthOf`]
+/// to count the characters, graphemes or display width
+///
 /// # Example
 /// ```
 /// use type_rules::prelude::*;
@@ -44,6 +53,84 @@ pub struct MinLength(pub usize);
 /// ```
 pub struct MaxLength(pub usize);
 
+/// Unit used to count the length of a string
+pub enum LengthUnit {
+    /// Number of bytes in UTF-8, as [`str::len`]
+    Bytes,
+    /// Number of Unicode scalar values, as [`str::chars`]
+    Chars,
+    /// Number of extended grapheme clusters, the characters
+    /// perceived by a user such as `"é"` or `"👍🏽"`
+    ///
+    /// You need the `unicode` feature to use it
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+    Graphemes,
+    /// Number of columns needed to display the string in a terminal,
+    /// wide characters such as CJK count for 2
+    ///
+    /// You need the `unicode` feature to use it
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+    Width,
+}
+
+impl LengthUnit {
+    fn count(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => value.len(),
+            LengthUnit::Chars => value.chars().count(),
+            #[cfg(feature = "unicode")]
+            LengthUnit::Graphemes => {
+                unicode_segmentation::UnicodeSegmentation::graphemes(value, true).count()
+            }
+            #[cfg(feature = "unicode")]
+            LengthUnit::Width => unicode_width::UnicodeWidthStr::width(value),
+        }
+    }
+}
+
+/// Rule to constraint the **minimum** and **maximum**
+/// length of any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str`, counted with a [`LengthUnit`]
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Username(#[rule(MinMaxLengthOf(LengthUnit::Chars, 3, 30))] String);
+///
+/// assert!(Username(String::from("Zoé")).check_validity().is_ok());
+/// ```
+pub struct MinMaxLengthOf(pub LengthUnit, pub usize, pub usize);
+
+/// Rule to constraint the **minimum**
+/// length of any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str`, counted with a [`LengthUnit`]
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Password(#[rule(MinLengthOf(LengthUnit::Chars, 8))] String);
+/// ```
+pub struct MinLengthOf(pub LengthUnit, pub usize);
+
+/// Rule to constraint the **maximum**
+/// length of any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str`, counted with a [`LengthUnit`]
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Message(#[rule(MaxLengthOf(LengthUnit::Chars, 280))] String);
+/// ```
+pub struct MaxLengthOf(pub LengthUnit, pub usize);
+
 impl<T: AsRef<str> + ?Sized> Rule<T> for MinMaxLength {
     fn check(&self, value: &T) -> Result<(), String> {
         let value = value.as_ref();
@@ -66,6 +153,26 @@ impl<T: AsRef<str> + ?Sized> Rule<T> for MinLength {
     }
 }
 
+impl<T: AsRef<str> + ?Sized> Rule<T> for MinMaxLengthOf {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let length = self.0.count(value.as_ref());
+        check_value_too_short(length, self.1)?;
+        check_value_too_long(length, self.2)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for MaxLengthOf {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_value_too_long(self.0.count(value.as_ref()), self.1)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for MinLengthOf {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_value_too_short(self.0.count(value.as_ref()), self.1)
+    }
+}
+
 fn check_value_too_short(length: usize, min_length: usize) -> Result<(), String> {
     if min_length > length {
         return Err(String::from("Value is too short"));
@@ -82,7 +189,10 @@ fn check_value_too_long(length: usize, max_length: usize) -> Result<(), String>
 
 #[cfg(test)]
 mod tests {
-    use crate::rules::{MaxLength, MinLength, MinMaxLength, Rule};
+    use crate::rules::{
+        LengthUnit, MaxLength, MaxLengthOf, MinLength, MinLengthOf, MinMaxLength, MinMaxLengthOf,
+        Rule,
+    };
     use claim::{assert_err, assert_ok};
 
     #[test]
@@ -113,4 +223,30 @@ mod tests {
     fn min_max_length_value_ok() {
         assert_ok!(MinMaxLength(0, 1).check("a"));
     }
+    #[test]
+    fn bytes_length() {
+        assert_err!(MaxLengthOf(LengthUnit::Bytes, 1).check("é"));
+    }
+    #[test]
+    fn chars_length() {
+        assert_ok!(MaxLengthOf(LengthUnit::Chars, 1).check("é"));
+        assert_err!(MinLengthOf(LengthUnit::Chars, 2).check("é"));
+    }
+    #[test]
+    fn min_max_chars_length() {
+        assert_ok!(MinMaxLengthOf(LengthUnit::Chars, 3, 3).check("Zoé"));
+    }
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn graphemes_length() {
+        assert_ok!(MaxLengthOf(LengthUnit::Graphemes, 1).check("👍🏽"));
+        assert_ok!(MaxLengthOf(LengthUnit::Graphemes, 1).check("e\u{301}"));
+        assert_err!(MaxLengthOf(LengthUnit::Chars, 1).check("👍🏽"));
+    }
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn width_length() {
+        assert_err!(MaxLengthOf(LengthUnit::Width, 3).check("日本"));
+        assert_ok!(MaxLengthOf(LengthUnit::Width, 4).check("日本"));
+    }
 }
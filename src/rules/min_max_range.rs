@@ -37,7 +37,7 @@ pub struct MinRange<T: PartialOrd<T>>(pub T);
 /// use chrono::prelude::*;
 ///
 /// #[derive(Validator)]
-/// struct BirthDate(#[rule(MaxRange(Utc::now()))] DateTime<Utc>);
+/// struct BirthDate(#[rule(MaxRange(now()))] DateTime<Utc>);
 /// ```
 pub struct MaxRange<T: PartialOrd<T>>(pub T);
 
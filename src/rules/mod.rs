@@ -1,34 +1,136 @@
 mod all;
 mod and;
 mod any;
+mod base64;
+#[cfg(any(feature = "chrono", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "chrono", feature = "time"))))]
+mod calendar;
+mod card_number;
+mod case_style;
+mod char_class;
+mod cidr;
+mod contains;
+mod date_time;
+mod email;
 mod eval;
+mod gtin;
+mod hex;
+mod hostname;
+mod iban;
+mod ip;
 mod is_in;
+mod isbn;
+#[cfg(feature = "iso")]
+#[cfg_attr(docsrs, doc(cfg(feature = "iso")))]
+mod iso_codes;
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+mod json;
+mod mac_address;
 mod min_max_length;
 mod min_max_range;
 mod min_max_size;
 mod opt;
 mod or;
+mod password_strength;
+mod phone;
+mod postal_code;
 #[cfg(feature = "regex")]
 #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
 mod regex;
+#[cfg(any(feature = "chrono", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "chrono", feature = "time"))))]
+mod relative_time;
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+mod safe_destination;
+#[cfg(feature = "semver")]
+#[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+mod semver;
+mod socket_address;
+#[cfg(feature = "tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tz")))]
+mod time_zone;
+mod ulid;
+mod unicode_security;
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+mod url;
+mod uuid;
 mod validate;
+mod vat;
 
 pub use self::all::*;
 pub use self::and::*;
 pub use self::any::*;
+pub use self::base64::*;
+pub use self::card_number::*;
+pub use self::case_style::*;
+pub use self::char_class::*;
+pub use self::cidr::*;
+pub use self::contains::*;
+pub use self::date_time::*;
+pub use self::email::*;
 pub use self::eval::*;
+pub use self::gtin::*;
+pub use self::hex::*;
+pub use self::hostname::*;
+pub use self::iban::*;
+pub use self::ip::*;
 pub use self::is_in::*;
+pub use self::isbn::*;
+pub use self::mac_address::*;
 pub use self::min_max_length::*;
 pub use self::min_max_range::*;
 pub use self::min_max_size::*;
 pub use self::opt::*;
 pub use self::or::*;
+pub use self::password_strength::*;
+pub use self::phone::*;
+pub use self::postal_code::*;
+pub use self::socket_address::*;
+pub use self::ulid::*;
+pub use self::unicode_security::*;
+pub use self::uuid::*;
 pub use self::validate::*;
+pub use self::vat::*;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "chrono", feature = "time"))))]
+pub use self::calendar::*;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "chrono", feature = "time"))))]
+pub use self::relative_time::*;
+
+#[cfg(feature = "iso")]
+#[cfg_attr(docsrs, doc(cfg(feature = "iso")))]
+pub use self::iso_codes::*;
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub use self::json::*;
 
 #[cfg(feature = "regex")]
 #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
 pub use self::regex::*;
 
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub use self::safe_destination::*;
+
+#[cfg(feature = "semver")]
+#[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+pub use self::semver::*;
+
+#[cfg(feature = "tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tz")))]
+pub use self::time_zone::*;
+
+#[cfg(feature = "url")]
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+pub use self::url::*;
+
 /// Define a rule for a type
 ///
 /// By implementing `Rule` for a type you define how
@@ -0,0 +1,316 @@
+use super::Rule;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Some of the most common passwords of the public breach lists,
+/// in lowercase and sorted, one per line
+const COMMON_PASSWORDS: &str = include_str!("data/common_passwords.txt");
+
+/// Set of the [`COMMON_PASSWORDS`], built at the first check
+static COMMON_PASSWORDS_SET: OnceLock<HashSet<&'static str>> = OnceLock::new();
+
+/// Size of the pool of symbols, used for the characters
+/// that are not ASCII letters or digits
+const SYMBOLS_POOL_SIZE: f64 = 33.0;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a strong password
+///
+/// The password can be required to have a minimum length, to contain
+/// some character classes, to have a minimum entropy and to not be a
+/// common password. The error explains the first failed criterion.
+///
+/// The entropy is estimated in bits as `length * log2(pool)`, where
+/// the pool is the sum of the sizes of the character classes used by
+/// the password: 26 for the lowercase letters, 26 for the uppercase
+/// letters, 10 for the digits and 33 for the symbols.
+///
+/// The common passwords are compared without considering the case, with
+/// the list embedded in the crate and optionally with a list loaded as
+/// [`CommonPasswords`].
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// const PASSWORD: PasswordStrength = PasswordStrength::new()
+///     .min_length(10)
+///     .require_lowercase()
+///     .require_uppercase()
+///     .require_digit()
+///     .min_entropy(50.0)
+///     .forbid_common();
+///
+/// #[derive(Validator)]
+/// struct Password(#[rule(PASSWORD)] String);
+///
+/// assert!(Password(String::from("Tr0ub4dor&3x")).check_validity().is_ok());
+/// assert!(Password(String::from("Password123")).check_validity().is_err());
+/// assert!(Password(String::from("tr0ub4dor&3x")).check_validity().is_err());
+/// ```
+pub struct PasswordStrength<'a> {
+    min_length: usize,
+    require_lowercase: bool,
+    require_uppercase: bool,
+    require_digit: bool,
+    require_symbol: bool,
+    min_entropy: f64,
+    forbid_common: bool,
+    common_passwords: Option<&'a CommonPasswords>,
+}
+
+impl<'a> PasswordStrength<'a> {
+    /// Accept any password
+    pub const fn new() -> Self {
+        PasswordStrength {
+            min_length: 0,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            min_entropy: 0.0,
+            forbid_common: false,
+            common_passwords: None,
+        }
+    }
+
+    /// Only accept the passwords of at least `min_length` characters
+    pub const fn min_length(self, min_length: usize) -> Self {
+        PasswordStrength { min_length, ..self }
+    }
+
+    /// Only accept the passwords with a lowercase letter
+    pub const fn require_lowercase(self) -> Self {
+        PasswordStrength {
+            require_lowercase: true,
+            ..self
+        }
+    }
+
+    /// Only accept the passwords with an uppercase letter
+    pub const fn require_uppercase(self) -> Self {
+        PasswordStrength {
+            require_uppercase: true,
+            ..self
+        }
+    }
+
+    /// Only accept the passwords with a digit
+    pub const fn require_digit(self) -> Self {
+        PasswordStrength {
+            require_digit: true,
+            ..self
+        }
+    }
+
+    /// Only accept the passwords with a character that is not an ASCII letter or digit
+    pub const fn require_symbol(self) -> Self {
+        PasswordStrength {
+            require_symbol: true,
+            ..self
+        }
+    }
+
+    /// Only accept the passwords with an estimated entropy of at least `min_entropy` bits
+    pub const fn min_entropy(self, min_entropy: f64) -> Self {
+        PasswordStrength {
+            min_entropy,
+            ..self
+        }
+    }
+
+    /// Reject the passwords of the list embedded in the crate
+    pub const fn forbid_common(self) -> Self {
+        PasswordStrength {
+            forbid_common: true,
+            ..self
+        }
+    }
+
+    /// Reject the passwords of the list embedded in the crate
+    /// and of `common_passwords`
+    pub const fn common_passwords(self, common_passwords: &'a CommonPasswords) -> Self {
+        PasswordStrength {
+            forbid_common: true,
+            common_passwords: Some(common_passwords),
+            ..self
+        }
+    }
+}
+
+impl<'a> Default for PasswordStrength<'a> {
+    fn default() -> Self {
+        PasswordStrength::new()
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for PasswordStrength<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let password = value.as_ref();
+        let length = password.chars().count();
+        if length < self.min_length {
+            return Err(format!(
+                "The password must contain at least {} characters",
+                self.min_length
+            ));
+        }
+        let classes = CharClasses::of(password);
+        let required_classes = [
+            (
+                self.require_lowercase,
+                classes.lowercase,
+                "a lowercase letter",
+            ),
+            (
+                self.require_uppercase,
+                classes.uppercase,
+                "an uppercase letter",
+            ),
+            (self.require_digit, classes.digit, "a digit"),
+            (self.require_symbol, classes.symbol, "a symbol"),
+        ];
+        for (required, present, class) in required_classes {
+            if required && !present {
+                return Err(format!("The password must contain {}", class));
+            }
+        }
+        if classes.entropy(length) < self.min_entropy {
+            return Err(String::from("The password is too easy to guess"));
+        }
+        if self.forbid_common {
+            let password = password.to_lowercase();
+            let is_common = COMMON_PASSWORDS_SET
+                .get_or_init(|| COMMON_PASSWORDS.lines().collect())
+                .contains(password.as_str())
+                || self
+                    .common_passwords
+                    .is_some_and(|list| list.0.contains(&password));
+            if is_common {
+                return Err(String::from("The password is too common"));
+            }
+        }
+        Ok(())
+    }
+}
+
+struct CharClasses {
+    lowercase: bool,
+    uppercase: bool,
+    digit: bool,
+    symbol: bool,
+}
+
+impl CharClasses {
+    fn of(password: &str) -> Self {
+        CharClasses {
+            lowercase: password.chars().any(|c| c.is_ascii_lowercase()),
+            uppercase: password.chars().any(|c| c.is_ascii_uppercase()),
+            digit: password.chars().any(|c| c.is_ascii_digit()),
+            symbol: password.chars().any(|c| !c.is_ascii_alphanumeric()),
+        }
+    }
+
+    fn entropy(&self, length: usize) -> f64 {
+        let pool = [
+            (self.lowercase, 26.0),
+            (self.uppercase, 26.0),
+            (self.digit, 10.0),
+            (self.symbol, SYMBOLS_POOL_SIZE),
+        ]
+        .iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, size)| size)
+        .sum::<f64>();
+        match pool {
+            0.0 => 0.0,
+            _ => length as f64 * pool.log2(),
+        }
+    }
+}
+
+/// List of common passwords to reject with [`PasswordStrength`],
+/// in addition to the list embedded in the crate
+///
+/// # Example
+/// ```no_run
+/// use std::sync::LazyLock;
+/// use type_rules::prelude::*;
+///
+/// static COMMON: LazyLock<CommonPasswords> = LazyLock::new(|| {
+///     CommonPasswords::from_file("/usr/share/wordlists/passwords.txt")
+///         .expect("The list of passwords can't be read")
+/// });
+///
+/// #[derive(Validator)]
+/// struct Password(#[rule(PasswordStrength::new().min_length(8).common_passwords(&COMMON))] String);
+/// ```
+pub struct CommonPasswords(HashSet<String>);
+
+impl CommonPasswords {
+    /// Load a list with one password per line
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::from_lines(&std::fs::read_to_string(path)?))
+    }
+
+    /// Create a list from a text with one password per line
+    pub fn from_lines(lines: &str) -> Self {
+        CommonPasswords(
+            lines
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_lowercase)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{CommonPasswords, PasswordStrength, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn password_length() {
+        let rule = PasswordStrength::new().min_length(8);
+        assert_ok!(rule.check("éèàùçâêî"));
+        let res_error_message = rule.check("abc").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The password must contain at least 8 characters"
+        );
+    }
+    #[test]
+    fn password_classes() {
+        let rule = PasswordStrength::new()
+            .require_lowercase()
+            .require_uppercase()
+            .require_digit()
+            .require_symbol();
+        assert_ok!(rule.check("aB3!"));
+        let res_error_message = rule.check("aB3c").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The password must contain a symbol");
+        assert_err!(rule.check("ab3!"));
+    }
+    #[test]
+    fn password_entropy() {
+        let rule = PasswordStrength::new().min_entropy(60.0);
+        assert_ok!(rule.check("correct horse battery"));
+        let res_error_message = rule.check("abcdefgh").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The password is too easy to guess");
+        assert_err!(rule.check(""));
+    }
+    #[test]
+    fn password_common() {
+        let rule = PasswordStrength::new().forbid_common();
+        assert_ok!(rule.check("b4ttery-st4ple"));
+        let res_error_message = rule.check("Passw0rd").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The password is too common");
+        let list = CommonPasswords::from_lines("hunter2\n b4ttery-st4ple \n");
+        let rule = PasswordStrength::new().common_passwords(&list);
+        assert_err!(rule.check("B4ttery-St4ple"));
+        assert_err!(rule.check("qwerty"));
+    }
+}
@@ -0,0 +1,204 @@
+use super::Rule;
+
+const MAX_E164_DIGITS: usize = 15;
+
+/// Numbering plan of a country: its calling code, its national trunk
+/// prefix, the allowed first digits and the minimum and maximum lengths
+/// of its national significant numbers
+pub(crate) struct PhoneCountry {
+    country: &'static str,
+    pub(crate) calling_code: &'static str,
+    pub(crate) trunk_prefix: &'static str,
+    leading_digits: &'static str,
+    lengths: (usize, usize),
+}
+
+const fn plan(
+    country: &'static str,
+    calling_code: &'static str,
+    trunk_prefix: &'static str,
+    leading_digits: &'static str,
+    lengths: (usize, usize),
+) -> PhoneCountry {
+    PhoneCountry {
+        country,
+        calling_code,
+        trunk_prefix,
+        leading_digits,
+        lengths,
+    }
+}
+
+const PHONE_COUNTRIES: &[PhoneCountry] = &[
+    plan("AE", "971", "0", "23456789", (8, 9)),
+    plan("AR", "54", "0", "123456789", (10, 10)),
+    plan("AT", "43", "0", "123456789", (4, 13)),
+    plan("AU", "61", "0", "23478", (9, 9)),
+    plan("BE", "32", "0", "123456789", (8, 9)),
+    plan("BR", "55", "0", "123456789", (10, 11)),
+    plan("CA", "1", "1", "23456789", (10, 10)),
+    plan("CH", "41", "0", "123456789", (9, 9)),
+    plan("CN", "86", "0", "123456789", (9, 11)),
+    plan("CZ", "420", "", "23456789", (9, 9)),
+    plan("DE", "49", "0", "123456789", (6, 13)),
+    plan("DK", "45", "", "23456789", (8, 8)),
+    plan("ES", "34", "", "6789", (9, 9)),
+    plan("FI", "358", "0", "123456789", (5, 12)),
+    plan("FR", "33", "0", "123456789", (9, 9)),
+    plan("GB", "44", "0", "1235789", (9, 10)),
+    plan("GR", "30", "", "2678", (10, 10)),
+    plan("IE", "353", "0", "123456789", (7, 9)),
+    plan("IL", "972", "0", "23456789", (8, 9)),
+    plan("IN", "91", "0", "123456789", (10, 10)),
+    plan("IT", "39", "", "03", (6, 11)),
+    plan("JP", "81", "0", "123456789", (9, 10)),
+    plan("KR", "82", "0", "123456789", (8, 10)),
+    plan("LU", "352", "", "123456789", (4, 11)),
+    plan("MX", "52", "", "123456789", (10, 10)),
+    plan("NL", "31", "0", "123456789", (9, 9)),
+    plan("NO", "47", "", "23456789", (8, 8)),
+    plan("NZ", "64", "0", "234789", (8, 10)),
+    plan("PL", "48", "", "123456789", (9, 9)),
+    plan("PT", "351", "", "29", (9, 9)),
+    plan("RU", "7", "8", "3489", (10, 10)),
+    plan("SE", "46", "0", "123456789", (7, 10)),
+    plan("SG", "65", "", "3689", (8, 8)),
+    plan("TR", "90", "0", "2345", (10, 10)),
+    plan("US", "1", "1", "23456789", (10, 10)),
+    plan("ZA", "27", "0", "12345678", (9, 9)),
+];
+
+impl PhoneCountry {
+    pub(crate) fn find(country: &str) -> Option<&'static PhoneCountry> {
+        PHONE_COUNTRIES.iter().find(|plan| plan.country == country)
+    }
+
+    /// Check if the digits of an E.164 number, without the `+`,
+    /// belong to this numbering plan
+    fn matches(&self, digits: &str) -> bool {
+        match digits.strip_prefix(self.calling_code) {
+            Some(national) => {
+                (self.lengths.0..=self.lengths.1).contains(&national.len())
+                    && national.starts_with(|c| self.leading_digits.contains(c))
+            }
+            None => false,
+        }
+    }
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a phone number in the E.164 format
+///
+/// The number is a `+` followed by at most 15 digits without separators,
+/// such as `+33612345678`. It can be restricted to some countries, the
+/// length and the first digits of the national number are then checked
+/// with the numbering plan of the country. Use the [`NormalizePhone`]
+/// sanitizer to turn user inputs into this format.
+///
+/// [`NormalizePhone`]: crate::sanitizers::NormalizePhone
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Contact(#[rule(Phone::new().countries(&["FR", "BE"]))] String);
+///
+/// assert!(Contact(String::from("+33612345678")).check_validity().is_ok());
+/// assert!(Contact(String::from("+3361234567")).check_validity().is_err());
+/// assert!(Contact(String::from("+14155552671")).check_validity().is_err());
+/// ```
+pub struct Phone<'a> {
+    countries: Option<&'a [&'a str]>,
+}
+
+impl<'a> Phone<'a> {
+    /// Accept any number in the E.164 format
+    pub const fn new() -> Self {
+        Phone { countries: None }
+    }
+
+    /// Only accept the numbers of these `countries`, as ISO 3166-1
+    /// alpha-2 codes such as `"FR"`
+    pub const fn countries(self, countries: &'a [&'a str]) -> Self {
+        Phone {
+            countries: Some(countries),
+        }
+    }
+}
+
+impl<'a> Default for Phone<'a> {
+    fn default() -> Self {
+        Phone::new()
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for Phone<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let digits = value
+            .as_ref()
+            .strip_prefix('+')
+            .filter(|digits| is_e164_digits(digits))
+            .ok_or_else(|| String::from("The phone number is not in the E.164 format"))?;
+        if let Some(countries) = self.countries {
+            let allowed = countries
+                .iter()
+                .filter_map(|country| PhoneCountry::find(country))
+                .any(|plan| plan.matches(digits));
+            if !allowed {
+                return Err(String::from(
+                    "The phone number is not a number of an allowed country",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_e164_digits(digits: &str) -> bool {
+    (2..=MAX_E164_DIGITS).contains(&digits.len())
+        && !digits.starts_with('0')
+        && digits.bytes().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Phone, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn phone_ok() {
+        assert_ok!(Phone::new().check("+33612345678"));
+        assert_ok!(Phone::new().check("+442079460958"));
+    }
+    #[test]
+    fn phone_format_err() {
+        let res_error_message = Phone::new()
+            .check("+33 6 12 34 56 78")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The phone number is not in the E.164 format"
+        );
+        assert_err!(Phone::new().check("0612345678"));
+        assert_err!(Phone::new().check("+0612345678"));
+        assert_err!(Phone::new().check("+1234567890123456"));
+    }
+    #[test]
+    fn phone_countries_ok() {
+        let rule = Phone::new().countries(&["US", "GB"]);
+        assert_ok!(rule.check("+14155552671"));
+        assert_ok!(rule.check("+442079460958"));
+    }
+    #[test]
+    fn phone_countries_err() {
+        let rule = Phone::new().countries(&["US"]);
+        let res_error_message = rule.check("+33612345678").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The phone number is not a number of an allowed country"
+        );
+        assert_err!(rule.check("+10155552671"));
+        assert_err!(Phone::new().countries(&["ZZ"]).check("+14155552671"));
+    }
+}
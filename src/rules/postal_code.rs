@@ -0,0 +1,209 @@
+use super::Rule;
+
+/// Formats of the postal codes of each country: `#` is a digit,
+/// `@` an uppercase letter, `*` a digit or an uppercase letter, a
+/// space is optional and other characters are literals
+const POSTAL_CODE_FORMATS: &[(&str, &[&str])] = &[
+    ("AR", &["####", "@####@@@"]),
+    ("AT", &["####"]),
+    ("AU", &["####"]),
+    ("BE", &["####"]),
+    ("BG", &["####"]),
+    ("BR", &["#####-###", "########"]),
+    ("CA", &["@#@ #@#"]),
+    ("CH", &["####"]),
+    ("CL", &["#######"]),
+    ("CN", &["######"]),
+    ("CO", &["######"]),
+    ("CZ", &["### ##"]),
+    ("DE", &["#####"]),
+    ("DK", &["####"]),
+    ("EE", &["#####"]),
+    ("ES", &["#####"]),
+    ("FI", &["#####"]),
+    ("FR", &["#####"]),
+    (
+        "GB",
+        &[
+            "@# #@@", "@## #@@", "@#@ #@@", "@@# #@@", "@@## #@@", "@@#@ #@@", "GIR 0AA",
+        ],
+    ),
+    ("GR", &["### ##"]),
+    ("HR", &["#####"]),
+    ("HU", &["####"]),
+    ("IE", &["@#* ****"]),
+    ("IL", &["#######"]),
+    ("IN", &["### ###"]),
+    ("IS", &["###"]),
+    ("IT", &["#####"]),
+    ("JP", &["###-####", "#######"]),
+    ("KR", &["#####"]),
+    ("LT", &["#####", "LT-#####"]),
+    ("LU", &["####", "L-####"]),
+    ("LV", &["LV-####"]),
+    ("MX", &["#####"]),
+    ("NL", &["#### @@"]),
+    ("NO", &["####"]),
+    ("NZ", &["####"]),
+    ("PL", &["##-###"]),
+    ("PT", &["####-###"]),
+    ("RO", &["######"]),
+    ("RU", &["######"]),
+    ("SE", &["### ##"]),
+    ("SG", &["######"]),
+    ("SI", &["####"]),
+    ("SK", &["### ##"]),
+    ("TR", &["#####"]),
+    ("TW", &["###", "#####", "######"]),
+    ("UA", &["#####"]),
+    ("US", &["#####", "#####-####"]),
+    ("ZA", &["####"]),
+];
+
+/// Countries without postal codes, whose addresses have an empty postal code
+const COUNTRIES_WITHOUT_POSTAL_CODES: &[&str] = &[
+    "AE", "AG", "AO", "AW", "BF", "BI", "BJ", "BO", "BS", "BW", "BZ", "CD", "CF", "CG", "CI", "CK",
+    "CM", "DJ", "DM", "ER", "FJ", "GA", "GD", "GH", "GM", "GQ", "GY", "HK", "JM", "KI", "KM", "KN",
+    "KP", "ML", "MO", "MR", "MW", "NR", "NU", "QA", "RW", "SB", "SC", "SL", "SR", "ST", "SY", "TD",
+    "TF", "TG", "TK", "TL", "TO", "TV", "UG", "VU", "YE", "ZW",
+];
+
+/// Longest postal code accepted for the countries without a known format
+const MAX_GENERIC_LENGTH: usize = 10;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a postal code of a country
+///
+/// The country is an ISO 3166-1 alpha-2 code such as `"FR"`, it can be
+/// another field of the struct. The letters of the postal code must be
+/// in uppercase and the optional spaces such as in `SW1A 1AA` can be
+/// omitted.
+///
+/// The postal code must be empty for the countries that have no postal
+/// codes, such as `"HK"`. For the other countries without a known
+/// format, the rule only checks that the postal code has 1 to 10
+/// uppercase letters, digits, spaces or hyphens; use [`CountryCode`]
+/// on the country to reject the codes that are not countries.
+///
+/// [`CountryCode`]: super::CountryCode
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Address {
+///     country: String,
+///     #[rule(PostalCode(&self.country))]
+///     postal_code: String,
+/// }
+///
+/// let address = Address {
+///     country: String::from("GB"),
+///     postal_code: String::from("SW1A 1AA"),
+/// };
+/// assert!(address.check_validity().is_ok());
+///
+/// let address = Address {
+///     country: String::from("US"),
+///     postal_code: String::from("SW1A 1AA"),
+/// };
+/// assert!(address.check_validity().is_err());
+/// ```
+pub struct PostalCode<C>(pub C);
+
+impl<C: AsRef<str>, T: AsRef<str> + ?Sized> Rule<T> for PostalCode<C> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let country = self.0.as_ref();
+        let value = value.as_ref();
+        let is_valid = match POSTAL_CODE_FORMATS
+            .iter()
+            .find(|(code, _)| *code == country)
+        {
+            Some((_, formats)) => formats.iter().any(|format| matches_format(value, format)),
+            None if COUNTRIES_WITHOUT_POSTAL_CODES.contains(&country) => value.is_empty(),
+            None => is_generic_postal_code(value),
+        };
+        match is_valid {
+            true => Ok(()),
+            false => Err(format!("The value is not a postal code of {:?}", country)),
+        }
+    }
+}
+
+fn is_generic_postal_code(value: &str) -> bool {
+    (1..=MAX_GENERIC_LENGTH).contains(&value.len())
+        && value
+            .bytes()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase() || c == b' ' || c == b'-')
+}
+
+fn matches_format(value: &str, format: &str) -> bool {
+    let mut value = value.bytes().peekable();
+    for f in format.bytes() {
+        let valid = match f {
+            b' ' => {
+                value.next_if_eq(&b' ');
+                true
+            }
+            b'#' => value.next().is_some_and(|c| c.is_ascii_digit()),
+            b'@' => value.next().is_some_and(|c| c.is_ascii_uppercase()),
+            b'*' => value
+                .next()
+                .is_some_and(|c| c.is_ascii_digit() || c.is_ascii_uppercase()),
+            _ => value.next() == Some(f),
+        };
+        if !valid {
+            return false;
+        }
+    }
+    value.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{PostalCode, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn postal_code_ok() {
+        assert_ok!(PostalCode("FR").check("75001"));
+        assert_ok!(PostalCode("US").check("94105-1804"));
+        assert_ok!(PostalCode("CA").check("K1A 0B1"));
+        assert_ok!(PostalCode("CA").check("K1A0B1"));
+        assert_ok!(PostalCode("NL").check("1012 AB"));
+        assert_ok!(PostalCode(String::from("LV")).check("LV-1050"));
+    }
+    #[test]
+    fn postal_code_err() {
+        let res_error_message = PostalCode("FR")
+            .check("7500")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The value is not a postal code of \"FR\""
+        );
+        assert_err!(PostalCode("CA").check("k1a 0b1"));
+        assert_err!(PostalCode("CA").check("K1A  0B1"));
+        assert_err!(PostalCode("PL").check("00 950"));
+    }
+    #[test]
+    fn postal_code_without_postal_system() {
+        assert_ok!(PostalCode("HK").check(""));
+        let res_error_message = PostalCode("HK")
+            .check("00000")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The value is not a postal code of \"HK\""
+        );
+    }
+    #[test]
+    fn postal_code_unknown_format() {
+        assert_ok!(PostalCode("PE").check("15001"));
+        assert_ok!(PostalCode("MT").check("VLT 1117"));
+        assert_err!(PostalCode("PE").check(""));
+        assert_err!(PostalCode("PE").check("15001\n"));
+        assert_err!(PostalCode("PE").check("12345678901"));
+    }
+}
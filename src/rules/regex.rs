@@ -13,16 +13,37 @@ use regex::Regex;
 /// #[derive(Validator)]
 /// struct Mail(#[rule(RegEx(r"^\S+@\S+\.\S+"))] String);
 /// ```
+///
+/// With the `derive` feature, a `RegEx` with a string literal is
+/// checked during the macro expansion, an invalid regex is a compile
+/// error and the regex is compiled only once for all the checks:
+///
+/// ```compile_fail
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Invalid(#[rule(RegEx(r"^(\S+"))] String);
+/// ```
 pub struct RegEx<'a>(pub &'a str);
 
+/// Already compiled [`RegEx`] generated by the derive macro
+#[doc(hidden)]
+pub struct StaticRegEx(pub &'static Regex);
+
 impl<'a, T: AsRef<str> + ?Sized> Rule<T> for RegEx<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let regex = Regex::new(self.0).expect("Invalid Regex");
+        check(&regex, value.as_ref())
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for StaticRegEx {
     fn check(&self, value: &T) -> Result<(), String> {
         check(self.0, value.as_ref())
     }
 }
 
-fn check(regex: &str, value: &str) -> Result<(), String> {
-    let regex = Regex::new(regex).expect("Invalid Regex");
+fn check(regex: &Regex, value: &str) -> Result<(), String> {
     if regex.is_match(value) {
         return Ok(());
     }
@@ -31,8 +52,9 @@ fn check(regex: &str, value: &str) -> Result<(), String> {
 
 #[cfg(test)]
 mod tests {
-    use crate::rules::{RegEx, Rule};
+    use crate::rules::{RegEx, Rule, StaticRegEx};
     use claim::{assert_err, assert_ok};
+    use regex::Regex;
 
     #[test]
     fn regex_ok() {
@@ -42,4 +64,14 @@ mod tests {
     fn regex_err() {
         assert_err!(RegEx(r"^\S+@\S+\.\S+").check("exampleexample.fr"));
     }
+    #[test]
+    fn static_regex_ok() {
+        let regex = Box::leak(Box::new(Regex::new(r"^\S+@\S+\.\S+").unwrap()));
+        assert_ok!(StaticRegEx(regex).check("example@example.fr"));
+    }
+    #[test]
+    fn static_regex_err() {
+        let regex = Box::leak(Box::new(Regex::new(r"^\S+@\S+\.\S+").unwrap()));
+        assert_err!(StaticRegEx(regex).check("exampleexample.fr"));
+    }
 }
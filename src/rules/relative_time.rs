@@ -0,0 +1,369 @@
+use super::date_time::date_from_days;
+use super::Rule;
+use crate::clock::{Clock, CurrentClock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Define a date, or a date and time, of `chrono` or `time` that the
+/// rules relative to the current time can check
+///
+/// The dates are considered at midnight UTC and the dates and
+/// times without offset are considered in UTC
+pub trait PointInTime {
+    /// Seconds and nanoseconds since 1970-01-01T00:00:00Z
+    fn unix_time(&self) -> (i64, u32);
+
+    /// Year, month and day of the value, in its own offset
+    fn ymd(&self) -> (i64, u32, u32);
+}
+
+/// Rule to constraint a date or a date and time of `chrono` or `time`
+/// to be before now
+///
+/// Now is the time of the [`CurrentClock`], use [`with_clock`] to check
+/// the rules relative to the current time at another time
+///
+/// You need the `chrono` or the `time` feature to use it
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use type_rules::clock::{with_clock, FixedClock};
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Publication(#[rule(Past())] DateTime<Utc>);
+///
+/// let publication = Publication(Utc.ymd(2022, 6, 1).and_hms(0, 0, 0));
+/// assert!(publication.check_validity().is_ok());
+///
+/// let clock = FixedClock(Utc.ymd(2022, 1, 1).and_hms(0, 0, 0).into());
+/// assert!(with_clock(clock, || publication.check_validity()).is_err());
+/// ```
+///
+/// [`with_clock`]: crate::clock::with_clock
+pub struct Past();
+
+/// Rule to constraint a date or a date and time of `chrono` or `time`
+/// to be after now
+///
+/// You need the `chrono` or the `time` feature to use it
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Appointment(#[rule(Future())] NaiveDateTime);
+///
+/// assert!(Appointment(NaiveDate::from_ymd(2022, 6, 1).and_hms(9, 0, 0)).check_validity().is_err());
+/// ```
+pub struct Future();
+
+/// Rule to constraint a date or a date and time of `chrono` or `time`
+/// to be at most at a [`Duration`] from now, in the past or the future
+///
+/// You need the `chrono` or the `time` feature to use it
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use std::time::Duration;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct SignedRequest(#[rule(Within(Duration::from_secs(300)))] DateTime<Utc>);
+///
+/// assert!(SignedRequest(Utc::now()).check_validity().is_ok());
+/// assert!(SignedRequest(Utc.ymd(2022, 6, 1).and_hms(0, 0, 0)).check_validity().is_err());
+/// ```
+pub struct Within(pub Duration);
+
+/// Rule to constraint a birth date of `chrono` or `time` to
+/// be at least some years ago
+///
+/// The age is counted in whole years, the people born a 29th
+/// of February get older the 1st of March of the common years
+///
+/// You need the `chrono` or the `time` feature to use it
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Adult(#[rule(MinAge(18))] NaiveDate);
+///
+/// assert!(Adult(NaiveDate::from_ymd(1985, 4, 12)).check_validity().is_ok());
+/// assert!(Adult(Utc::today().naive_utc()).check_validity().is_err());
+/// ```
+pub struct MinAge(pub u32);
+
+/// Rule to constraint a birth date of `chrono` or `time` to
+/// be at most some years ago
+///
+/// The age is counted in whole years, as for [`MinAge`]
+///
+/// You need the `chrono` or the `time` feature to use it
+///
+/// # Example
+/// ```
+/// use chrono::prelude::*;
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Child(#[rule(MaxAge(17))] NaiveDate);
+///
+/// assert!(Child(NaiveDate::from_ymd(1985, 4, 12)).check_validity().is_err());
+/// ```
+pub struct MaxAge(pub u32);
+
+impl<T: PointInTime + ?Sized> Rule<T> for Past {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match value.unix_time() < unix_time(CurrentClock().now()) {
+            true => Ok(()),
+            false => Err(String::from("The date is not in the past")),
+        }
+    }
+}
+
+impl<T: PointInTime + ?Sized> Rule<T> for Future {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match value.unix_time() > unix_time(CurrentClock().now()) {
+            true => Ok(()),
+            false => Err(String::from("The date is not in the future")),
+        }
+    }
+}
+
+impl<T: PointInTime + ?Sized> Rule<T> for Within {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (seconds, nanos) = value.unix_time();
+        let (now_seconds, now_nanos) = unix_time(CurrentClock().now());
+        let distance = (i128::from(seconds) * 1_000_000_000 + i128::from(nanos))
+            - (i128::from(now_seconds) * 1_000_000_000 + i128::from(now_nanos));
+        match distance.unsigned_abs() <= self.0.as_nanos() {
+            true => Ok(()),
+            false => Err(format!("The date is more than {:?} away from now", self.0)),
+        }
+    }
+}
+
+impl<T: PointInTime + ?Sized> Rule<T> for MinAge {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match age(value.ymd(), CurrentClock().now()) >= i64::from(self.0) {
+            true => Ok(()),
+            false => Err(format!("The age must be at least {} years", self.0)),
+        }
+    }
+}
+
+impl<T: PointInTime + ?Sized> Rule<T> for MaxAge {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match age(value.ymd(), CurrentClock().now()) <= i64::from(self.0) {
+            true => Ok(()),
+            false => Err(format!("The age must be at most {} years", self.0)),
+        }
+    }
+}
+
+fn unix_time(time: SystemTime) -> (i64, u32) {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+        Err(err) => {
+            let duration = err.duration();
+            match duration.subsec_nanos() {
+                0 => (-(duration.as_secs() as i64), 0),
+                nanos => (-(duration.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    }
+}
+
+/// Whole years between a birth date and the date of `now` in UTC
+fn age((year, month, day): (i64, u32, u32), now: SystemTime) -> i64 {
+    let (now_year, now_month, now_day) =
+        date_from_days(unix_time(now).0.div_euclid(SECONDS_PER_DAY));
+    let age = now_year - year;
+    match (now_month, now_day) < (month, day) {
+        true => age - 1,
+        false => age,
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::PointInTime;
+    use chrono::Datelike;
+
+    fn chrono_ymd(date: &impl Datelike) -> (i64, u32, u32) {
+        (date.year().into(), date.month(), date.day())
+    }
+
+    impl<Tz: chrono::TimeZone> PointInTime for chrono::DateTime<Tz> {
+        fn unix_time(&self) -> (i64, u32) {
+            (self.timestamp(), self.timestamp_subsec_nanos())
+        }
+
+        fn ymd(&self) -> (i64, u32, u32) {
+            chrono_ymd(&self.naive_local())
+        }
+    }
+
+    impl PointInTime for chrono::NaiveDateTime {
+        fn unix_time(&self) -> (i64, u32) {
+            (self.timestamp(), self.timestamp_subsec_nanos())
+        }
+
+        fn ymd(&self) -> (i64, u32, u32) {
+            chrono_ymd(self)
+        }
+    }
+
+    impl PointInTime for chrono::NaiveDate {
+        fn unix_time(&self) -> (i64, u32) {
+            (self.and_hms(0, 0, 0).timestamp(), 0)
+        }
+
+        fn ymd(&self) -> (i64, u32, u32) {
+            chrono_ymd(self)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::PointInTime;
+
+    fn time_ymd(date: ::time::Date) -> (i64, u32, u32) {
+        (
+            date.year().into(),
+            u8::from(date.month()).into(),
+            date.day().into(),
+        )
+    }
+
+    impl PointInTime for ::time::OffsetDateTime {
+        fn unix_time(&self) -> (i64, u32) {
+            (self.unix_timestamp(), self.nanosecond())
+        }
+
+        fn ymd(&self) -> (i64, u32, u32) {
+            time_ymd(self.date())
+        }
+    }
+
+    impl PointInTime for ::time::PrimitiveDateTime {
+        fn unix_time(&self) -> (i64, u32) {
+            self.assume_utc().unix_time()
+        }
+
+        fn ymd(&self) -> (i64, u32, u32) {
+            time_ymd(self.date())
+        }
+    }
+
+    impl PointInTime for ::time::Date {
+        fn unix_time(&self) -> (i64, u32) {
+            self.midnight().assume_utc().unix_time()
+        }
+
+        fn ymd(&self) -> (i64, u32, u32) {
+            time_ymd(*self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::clock::FixedClock;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    /// 2022-06-01T12:00:00Z
+    fn clock() -> FixedClock {
+        FixedClock(UNIX_EPOCH + Duration::from_secs(1_654_084_800))
+    }
+
+    #[cfg(feature = "chrono")]
+    mod chrono_tests {
+        use super::clock;
+        use crate::clock::with_clock;
+        use crate::rules::{Future, MaxAge, MinAge, Past, Rule, Within};
+        use chrono::prelude::*;
+        use claim::{assert_err, assert_ok};
+        use std::time::{Duration, SystemTime};
+
+        #[test]
+        fn past_and_future() {
+            let yesterday = Utc.ymd(2022, 5, 31).and_hms(12, 0, 0);
+            let now = Utc.ymd(2022, 6, 1).and_hms(12, 0, 0);
+            assert_eq!(SystemTime::from(now), clock().0);
+            with_clock(clock(), || {
+                assert_ok!(Past().check(&yesterday));
+                assert_err!(Future().check(&yesterday));
+                assert_err!(Past().check(&now));
+                assert_err!(Future().check(&now.naive_utc()));
+                let res_error_message = Future()
+                    .check(&NaiveDate::from_ymd(2022, 6, 1))
+                    .expect_err("Should be an Err");
+                assert_eq!(res_error_message, "The date is not in the future");
+                assert_ok!(Past().check(&NaiveDate::from_ymd(1900, 1, 1).and_hms(0, 0, 1)));
+                assert_err!(Past().check(&NaiveDate::from_ymd(2100, 1, 1)));
+            });
+        }
+        #[test]
+        fn within() {
+            let rule = Within(Duration::from_secs(3600));
+            let paris = FixedOffset::east(2 * 3600);
+            with_clock(clock(), || {
+                assert_ok!(rule.check(&paris.ymd(2022, 6, 1).and_hms(15, 0, 0)));
+                assert_err!(rule.check(&paris.ymd(2022, 6, 1).and_hms(15, 0, 1)));
+                assert_ok!(rule.check(&NaiveDate::from_ymd(2022, 6, 1).and_hms(11, 0, 0)));
+            });
+        }
+        #[test]
+        fn age() {
+            with_clock(clock(), || {
+                assert_ok!(MinAge(18).check(&NaiveDate::from_ymd(2004, 6, 1)));
+                let res_error_message = MinAge(18)
+                    .check(&NaiveDate::from_ymd(2004, 6, 2))
+                    .expect_err("Should be an Err");
+                assert_eq!(res_error_message, "The age must be at least 18 years");
+                assert_ok!(MaxAge(17).check(&NaiveDate::from_ymd(2004, 6, 2)));
+                assert_err!(MaxAge(17).check(&NaiveDate::from_ymd(2004, 6, 1)));
+            });
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod time_tests {
+        use super::clock;
+        use crate::clock::with_clock;
+        use crate::rules::{Future, MinAge, Past, Rule, Within};
+        use ::time::macros::{date, datetime};
+        use claim::{assert_err, assert_ok};
+        use std::time::Duration;
+
+        #[test]
+        fn past_and_future() {
+            with_clock(clock(), || {
+                assert_ok!(Past().check(&datetime!(2022-06-01 11:59:59 UTC)));
+                assert_ok!(Future().check(&datetime!(2022-06-01 12:00:01)));
+                assert_err!(Future().check(&date!(2022 - 06 - 01)));
+            });
+        }
+        #[test]
+        fn within_and_age() {
+            let rule = Within(Duration::from_secs(60));
+            with_clock(clock(), || {
+                assert_ok!(rule.check(&datetime!(2022-06-01 14:01:00 +2)));
+                assert_err!(rule.check(&datetime!(2022-06-01 14:01:01 +2)));
+                assert_ok!(MinAge(18).check(&date!(2004 - 06 - 01)));
+                assert_err!(MinAge(18).check(&date!(2004 - 06 - 02)));
+            });
+        }
+    }
+}
@@ -0,0 +1,414 @@
+use super::cidr::{cidr_contains, parse_cidr};
+use super::Rule;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// Special-purpose range of IP addresses that should not be
+/// reached with a user supplied destination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressRange {
+    /// `0.0.0.0/8` and `::`
+    Unspecified,
+    /// `127.0.0.0/8`, `::1` and the `localhost` hostnames
+    Loopback,
+    /// `10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16` (RFC 1918),
+    /// `fc00::/7` (unique local addresses) and `fec0::/10` (deprecated
+    /// site-local addresses)
+    Private,
+    /// `100.64.0.0/10`, used by carrier-grade NAT (RFC 6598)
+    SharedAddressSpace,
+    /// `169.254.0.0/16` and `fe80::/10`
+    LinkLocal,
+    /// Instance metadata services of the cloud providers such as
+    /// `169.254.169.254` or `metadata.google.internal`
+    CloudMetadata,
+    /// `224.0.0.0/4` and `ff00::/8`
+    Multicast,
+    /// `255.255.255.255`
+    Broadcast,
+    /// `240.0.0.0/4`
+    Reserved,
+}
+
+impl AddressRange {
+    /// Classify an IP address, `None` for a public address
+    ///
+    /// The IPv4 addresses mapped, translated, compatible or embedded
+    /// in a 6to4 address such as `::ffff:127.0.0.1`, `::127.0.0.1`
+    /// or `2002:7f00:1::` are classified as IPv4 addresses
+    pub fn of(ip: IpAddr) -> Option<AddressRange> {
+        match ip {
+            IpAddr::V4(ip) => Self::of_ipv4(ip),
+            IpAddr::V6(ip) => Self::of_ipv6(ip),
+        }
+    }
+
+    fn of_ipv4(ip: Ipv4Addr) -> Option<AddressRange> {
+        let octets = ip.octets();
+        let range = match octets {
+            [169, 254, 169, 254] | [169, 254, 170, 2] | [100, 100, 100, 200] => {
+                AddressRange::CloudMetadata
+            }
+            [0, ..] => AddressRange::Unspecified,
+            [127, ..] => AddressRange::Loopback,
+            [10, ..] | [192, 168, ..] => AddressRange::Private,
+            [172, b, ..] if (16..32).contains(&b) => AddressRange::Private,
+            [100, b, ..] if (64..128).contains(&b) => AddressRange::SharedAddressSpace,
+            [169, 254, ..] => AddressRange::LinkLocal,
+            [255, 255, 255, 255] => AddressRange::Broadcast,
+            [a, ..] if (224..240).contains(&a) => AddressRange::Multicast,
+            [a, ..] if a >= 240 => AddressRange::Reserved,
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    fn of_ipv6(ip: Ipv6Addr) -> Option<AddressRange> {
+        let segments = ip.segments();
+        let range = match segments {
+            [0, 0, 0, 0, 0, 0xffff, ..] | [0x64, 0xff9b, 0, 0, 0, 0, ..] => {
+                let [a, b] = segments[6].to_be_bytes();
+                let [c, d] = segments[7].to_be_bytes();
+                return Self::of_ipv4(Ipv4Addr::new(a, b, c, d));
+            }
+            [0x2002, high, low, ..] => {
+                let [a, b] = high.to_be_bytes();
+                let [c, d] = low.to_be_bytes();
+                return Self::of_ipv4(Ipv4Addr::new(a, b, c, d));
+            }
+            [0xfd00, 0x0ec2, 0, 0, 0, 0, 0, 0x0254] => AddressRange::CloudMetadata,
+            [0, 0, 0, 0, 0, 0, 0, 0] => AddressRange::Unspecified,
+            [0, 0, 0, 0, 0, 0, 0, 1] => AddressRange::Loopback,
+            [0, 0, 0, 0, 0, 0, ..] => {
+                let [a, b] = segments[6].to_be_bytes();
+                let [c, d] = segments[7].to_be_bytes();
+                return Self::of_ipv4(Ipv4Addr::new(a, b, c, d));
+            }
+            [a, ..] if a & 0xfe00 == 0xfc00 || a & 0xffc0 == 0xfec0 => AddressRange::Private,
+            [a, ..] if a & 0xffc0 == 0xfe80 => AddressRange::LinkLocal,
+            [a, ..] if a & 0xff00 == 0xff00 => AddressRange::Multicast,
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    fn of_domain(domain: &str) -> Option<AddressRange> {
+        let domain = domain
+            .strip_suffix('.')
+            .unwrap_or(domain)
+            .to_ascii_lowercase();
+        match domain.as_str() {
+            "localhost" => Some(AddressRange::Loopback),
+            "metadata" | "metadata.google.internal" => Some(AddressRange::CloudMetadata),
+            domain if domain.ends_with(".localhost") => Some(AddressRange::Loopback),
+            _ => None,
+        }
+    }
+
+    fn error_message(&self) -> &'static str {
+        match self {
+            AddressRange::Unspecified => "The destination is an unspecified address",
+            AddressRange::Loopback => "The destination is a loopback address",
+            AddressRange::Private => "The destination is a private address",
+            AddressRange::SharedAddressSpace => "The destination is a shared (CGNAT) address",
+            AddressRange::LinkLocal => "The destination is a link-local address",
+            AddressRange::CloudMetadata => "The destination is a cloud metadata address",
+            AddressRange::Multicast => "The destination is a multicast address",
+            AddressRange::Broadcast => "The destination is a broadcast address",
+            AddressRange::Reserved => "The destination is a reserved address",
+        }
+    }
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not point at an internal destination
+///
+/// The value can be an IP address, an URL, a host or a host and a port
+/// such as `example.com:443` or `[::1]:8080`. The host is parsed
+/// as the WHATWG URL standard, so forms such as `http://0x7f.1/` are
+/// recognized as `127.0.0.1`, even with a non-special scheme such as
+/// `gopher://` whose host is opaque. All the [`AddressRange`] are rejected by
+/// default, some of them can be allowed, and CIDR blocks can be
+/// explicitly allowed or denied.
+///
+/// No DNS resolution is made, a domain name is only rejected if it is
+/// `localhost` or a known cloud metadata hostname. The resolved address
+/// must still be checked when connecting to prevent DNS rebinding.
+///
+/// You need the `url` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Callback(
+///     #[rule(Url::new().schemes(&["https"]), SafeDestination::new())]
+///     String
+/// );
+///
+/// assert!(Callback(String::from("https://example.com/hook")).check_validity().is_ok());
+/// assert!(Callback(String::from("https://169.254.169.254/latest")).check_validity().is_err());
+/// assert!(Callback(String::from("https://[::ffff:10.0.0.1]/")).check_validity().is_err());
+/// ```
+pub struct SafeDestination<'a> {
+    allowed_ranges: &'a [AddressRange],
+    allowed_cidrs: &'a [&'a str],
+    denied_cidrs: &'a [&'a str],
+}
+
+impl<'a> SafeDestination<'a> {
+    /// Reject all the [`AddressRange`]
+    pub const fn new() -> Self {
+        SafeDestination {
+            allowed_ranges: &[],
+            allowed_cidrs: &[],
+            denied_cidrs: &[],
+        }
+    }
+
+    /// Accept the addresses of these `ranges`
+    pub const fn allow_ranges(self, ranges: &'a [AddressRange]) -> Self {
+        SafeDestination {
+            allowed_ranges: ranges,
+            ..self
+        }
+    }
+
+    /// Accept the addresses in these CIDR blocks, even if they are in a
+    /// rejected range or a denied block
+    ///
+    /// An invalid block makes every check return an error
+    pub const fn allow_cidrs(self, cidrs: &'a [&'a str]) -> Self {
+        SafeDestination {
+            allowed_cidrs: cidrs,
+            ..self
+        }
+    }
+
+    /// Reject the addresses in these CIDR blocks
+    ///
+    /// An invalid block makes every check return an error
+    pub const fn deny_cidrs(self, cidrs: &'a [&'a str]) -> Self {
+        SafeDestination {
+            denied_cidrs: cidrs,
+            ..self
+        }
+    }
+
+    fn check_ip(&self, ip: IpAddr) -> Result<(), String> {
+        if in_cidrs(self.allowed_cidrs, ip)? {
+            return Ok(());
+        }
+        if in_cidrs(self.denied_cidrs, ip)? {
+            return Err(String::from("The destination is a denied address"));
+        }
+        self.check_range(AddressRange::of(ip))
+    }
+
+    fn check_range(&self, range: Option<AddressRange>) -> Result<(), String> {
+        match range {
+            Some(range) if !self.allowed_ranges.contains(&range) => {
+                Err(String::from(range.error_message()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> Default for SafeDestination<'a> {
+    fn default() -> Self {
+        SafeDestination::new()
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for SafeDestination<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        if let Ok(ip) = value.parse::<IpAddr>() {
+            return self.check_ip(ip);
+        }
+        if let Ok(address) = value.parse::<SocketAddr>() {
+            return self.check_ip(address.ip());
+        }
+        let host = match authority_host(value) {
+            Some(host) => ::url::Host::parse(host).ok(),
+            None => match ::url::Url::parse(value) {
+                Ok(url) => url.host().map(|host| host.to_owned()),
+                Err(_) => ::url::Host::parse(value).ok(),
+            },
+        };
+        match host {
+            Some(::url::Host::Ipv4(ip)) => self.check_ip(IpAddr::V4(ip)),
+            Some(::url::Host::Ipv6(ip)) => self.check_ip(IpAddr::V6(ip)),
+            // The hosts of the non-special schemes such as `gopher://` are
+            // opaque, so the IP addresses are parsed as a special host
+            Some(::url::Host::Domain(domain)) => match ::url::Host::parse(&domain) {
+                Ok(::url::Host::Ipv4(ip)) => self.check_ip(IpAddr::V4(ip)),
+                Ok(::url::Host::Ipv6(ip)) => self.check_ip(IpAddr::V6(ip)),
+                _ => self.check_range(AddressRange::of_domain(&domain)),
+            },
+            None => Err(String::from("The destination is invalid")),
+        }
+    }
+}
+
+fn in_cidrs(cidrs: &[&str], ip: IpAddr) -> Result<bool, String> {
+    for cidr in cidrs {
+        match parse_cidr(cidr) {
+            Some(block) if cidr_contains(block, ip) => return Ok(true),
+            Some(_) => {}
+            None => return Err(format!("The CIDR block {:?} is invalid", cidr)),
+        }
+    }
+    Ok(false)
+}
+
+/// Host of a value in the authority form `host:port`, such as
+/// `example.com:443`, that would be parsed as an URL of scheme `example.com`
+fn authority_host(value: &str) -> Option<&str> {
+    let (host, port) = value.rsplit_once(':')?;
+    match !host.contains('/') && !port.is_empty() && port.bytes().all(|c| c.is_ascii_digit()) {
+        true => Some(host),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{AddressRange, Rule, SafeDestination};
+    use claim::{assert_err, assert_ok};
+
+    const RULE: SafeDestination = SafeDestination::new();
+
+    #[test]
+    fn safe_destination_ok() {
+        assert_ok!(RULE.check("https://example.com/hook"));
+        assert_ok!(RULE.check("8.8.8.8"));
+        assert_ok!(RULE.check("https://[2001:4860:4860::8888]/"));
+        assert_ok!(RULE.check("example.com"));
+    }
+    #[test]
+    fn safe_destination_ranges() {
+        assert_eq!(
+            RULE.check("http://127.0.0.1:8080/").unwrap_err(),
+            "The destination is a loopback address"
+        );
+        assert_eq!(
+            RULE.check("http://0x7f.1/").unwrap_err(),
+            "The destination is a loopback address"
+        );
+        assert_eq!(
+            RULE.check("http://LOCALHOST./").unwrap_err(),
+            "The destination is a loopback address"
+        );
+        assert_eq!(
+            RULE.check("172.20.1.1").unwrap_err(),
+            "The destination is a private address"
+        );
+        assert_eq!(
+            RULE.check("fd12::1").unwrap_err(),
+            "The destination is a private address"
+        );
+        assert_eq!(
+            RULE.check("100.64.0.1").unwrap_err(),
+            "The destination is a shared (CGNAT) address"
+        );
+        assert_eq!(
+            RULE.check("http://[fe80::1]/").unwrap_err(),
+            "The destination is a link-local address"
+        );
+        assert_eq!(
+            RULE.check("http://metadata.google.internal/").unwrap_err(),
+            "The destination is a cloud metadata address"
+        );
+        assert_eq!(
+            RULE.check("http://[::ffff:169.254.169.254]/").unwrap_err(),
+            "The destination is a cloud metadata address"
+        );
+    }
+    #[test]
+    fn safe_destination_non_special_schemes() {
+        assert_eq!(
+            RULE.check("gopher://127.0.0.1:6379/_x").unwrap_err(),
+            "The destination is a loopback address"
+        );
+        assert_eq!(
+            RULE.check("foo://10.0.0.1/").unwrap_err(),
+            "The destination is a private address"
+        );
+        assert_eq!(
+            RULE.check("redis://169.254.169.254/").unwrap_err(),
+            "The destination is a cloud metadata address"
+        );
+        assert_err!(RULE.check("redis://0x7f.1/"));
+        assert_err!(RULE.check("redis://localhost/"));
+        assert_ok!(RULE.check("redis://example.com:6379/"));
+    }
+    #[test]
+    fn safe_destination_embedded_ipv4() {
+        assert_eq!(
+            RULE.check("http://[::127.0.0.1]/").unwrap_err(),
+            "The destination is a loopback address"
+        );
+        assert_eq!(
+            RULE.check("http://[2002:7f00:1::]/").unwrap_err(),
+            "The destination is a loopback address"
+        );
+        assert_eq!(
+            RULE.check("2002:a9fe:a9fe::1").unwrap_err(),
+            "The destination is a cloud metadata address"
+        );
+        assert_ok!(RULE.check("2002:808:808::1"));
+    }
+    #[test]
+    fn safe_destination_site_local() {
+        assert_eq!(
+            RULE.check("http://[fec0::1]/").unwrap_err(),
+            "The destination is a private address"
+        );
+        assert_eq!(
+            RULE.check("feff::1").unwrap_err(),
+            "The destination is a private address"
+        );
+    }
+    #[test]
+    fn safe_destination_allow_ranges() {
+        let rule = SafeDestination::new().allow_ranges(&[AddressRange::Private]);
+        assert_ok!(rule.check("10.0.0.1"));
+        assert_err!(rule.check("127.0.0.1"));
+    }
+    #[test]
+    fn safe_destination_cidrs() {
+        let rule = SafeDestination::new()
+            .allow_cidrs(&["10.1.0.0/16"])
+            .deny_cidrs(&["203.0.113.0/24"]);
+        assert_ok!(rule.check("10.1.2.3"));
+        assert_err!(rule.check("10.2.2.3"));
+        assert_err!(rule.check("https://203.0.113.7/"));
+    }
+    #[test]
+    fn safe_destination_invalid_cidr() {
+        let rule = SafeDestination::new().deny_cidrs(&["203.0.113.0/33"]);
+        let res_error_message = rule.check("1.1.1.1").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The CIDR block \"203.0.113.0/33\" is invalid"
+        );
+        assert_err!(SafeDestination::new()
+            .allow_cidrs(&["10.0.0.0"])
+            .check("10.0.0.1"));
+    }
+    #[test]
+    fn safe_destination_authority() {
+        assert_ok!(RULE.check("example.com:443"));
+        assert_ok!(RULE.check("1.1.1.1:53"));
+        assert_err!(RULE.check("localhost:8080"));
+        assert_err!(RULE.check("127.0.0.1:6379"));
+        assert_err!(RULE.check("[::1]:80"));
+        assert_err!(RULE.check("0x7f.1:80"));
+    }
+    #[test]
+    fn address_range_public() {
+        assert_eq!(AddressRange::of("1.1.1.1".parse().unwrap()), None);
+    }
+}
@@ -0,0 +1,91 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a semantic version such as `1.2.3-beta.1`
+///
+/// The version can be required to match a version requirement with
+/// the syntax of Cargo, such as `^1.2` or `>=1.0, <2.0`
+///
+/// You need the `semver` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Release(#[rule(Semver::new().matches(">=1.0, <2.0"))] String);
+///
+/// assert!(Release(String::from("1.4.0")).check_validity().is_ok());
+/// assert!(Release(String::from("2.0.0")).check_validity().is_err());
+/// assert!(Release(String::from("1.4")).check_validity().is_err());
+/// ```
+pub struct Semver<'a> {
+    requirement: Option<&'a str>,
+}
+
+impl<'a> Semver<'a> {
+    /// Accept any semantic version
+    pub const fn new() -> Self {
+        Semver { requirement: None }
+    }
+
+    /// Only accept the versions that match the `requirement`
+    pub const fn matches(self, requirement: &'a str) -> Self {
+        Semver {
+            requirement: Some(requirement),
+        }
+    }
+}
+
+impl<'a> Default for Semver<'a> {
+    fn default() -> Self {
+        Semver::new()
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for Semver<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let version = ::semver::Version::parse(value.as_ref())
+            .map_err(|err| format!("The value is not a semantic version: {}", err))?;
+        if let Some(requirement) = self.requirement {
+            let requirement = ::semver::VersionReq::parse(requirement)
+                .map_err(|err| format!("The version requirement is invalid: {}", err))?;
+            if !requirement.matches(&version) {
+                return Err(format!(
+                    "The version doesn't match the requirement `{}`",
+                    requirement
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Rule, Semver};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn semver_ok() {
+        assert_ok!(Semver::new().check("0.2.3"));
+        assert_ok!(Semver::new().check("1.0.0-alpha.1+build.5"));
+    }
+    #[test]
+    fn semver_err() {
+        assert_err!(Semver::new().check("1.0"));
+        assert_err!(Semver::new().check("v1.0.0"));
+        assert_err!(Semver::new().check("01.0.0"));
+    }
+    #[test]
+    fn semver_matches() {
+        let rule = Semver::new().matches("^1.2");
+        assert_ok!(rule.check("1.9.0"));
+        let res_error_message = rule.check("1.1.0").expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The version doesn't match the requirement `^1.2`"
+        );
+        assert_err!(Semver::new().matches("^x").check("1.0.0"));
+    }
+}
@@ -0,0 +1,85 @@
+use super::hostname::is_valid_hostname;
+use super::Rule;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be a `host:port` address
+///
+/// The host can be an IPv4 address, an IPv6 address between brackets
+/// or a [`Hostname`](super::Hostname), the port is required
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Listen(#[rule(SocketAddress())] String);
+///
+/// assert!(Listen(String::from("0.0.0.0:8080")).check_validity().is_ok());
+/// assert!(Listen(String::from("[::1]:8080")).check_validity().is_ok());
+/// assert!(Listen(String::from("db.internal:5432")).check_validity().is_ok());
+/// assert!(Listen(String::from("db.internal")).check_validity().is_err());
+/// ```
+pub struct SocketAddress();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for SocketAddress {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let (host, port) = value
+            .as_ref()
+            .rsplit_once(':')
+            .ok_or_else(|| String::from("The socket address is missing a port"))?;
+        if port.is_empty()
+            || !port.bytes().all(|c| c.is_ascii_digit())
+            || port.parse::<u16>().is_err()
+        {
+            return Err(String::from("The port of the socket address is invalid"));
+        }
+        let valid_host = match host
+            .strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+        {
+            Some(ipv6) => ipv6.parse::<Ipv6Addr>().is_ok(),
+            None => host.parse::<Ipv4Addr>().is_ok() || is_valid_hostname(host),
+        };
+        match valid_host {
+            true => Ok(()),
+            false => Err(String::from("The host of the socket address is invalid")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Rule, SocketAddress};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn socket_address_ok() {
+        assert_ok!(SocketAddress().check("127.0.0.1:0"));
+        assert_ok!(SocketAddress().check("[2001:db8::1]:65535"));
+        assert_ok!(SocketAddress().check("localhost:80"));
+    }
+    #[test]
+    fn socket_address_port_err() {
+        let res_error_message = SocketAddress()
+            .check("localhost:65536")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The port of the socket address is invalid"
+        );
+        assert_err!(SocketAddress().check("localhost:"));
+    }
+    #[test]
+    fn socket_address_host_err() {
+        let res_error_message = SocketAddress()
+            .check("2001:db8::1:80")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The host of the socket address is invalid"
+        );
+        assert_err!(SocketAddress().check(":80"));
+        assert_err!(SocketAddress().check("999.999.999.999:80"));
+    }
+}
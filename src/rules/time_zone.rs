@@ -0,0 +1,55 @@
+use super::Rule;
+
+/// Names of the zones and the links of the IANA time zone database
+const TIME_ZONES: &str = include_str!("data/time_zones.txt");
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an IANA time zone name, such as
+/// `Europe/Paris`
+///
+/// The canonical names and their links such as `US/Eastern` or `UTC`
+/// are accepted, the case is considered
+///
+/// You need the `tz` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Settings(#[rule(TimeZone())] String);
+///
+/// assert!(Settings(String::from("America/New_York")).check_validity().is_ok());
+/// assert!(Settings(String::from("America/Paris")).check_validity().is_err());
+/// ```
+pub struct TimeZone();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for TimeZone {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        match TIME_ZONES.lines().any(|time_zone| time_zone == value) {
+            true => Ok(()),
+            false => Err(String::from("The value is not an IANA time zone")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Rule, TimeZone};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn time_zone_ok() {
+        assert_ok!(TimeZone().check("Europe/Paris"));
+        assert_ok!(TimeZone().check("America/Argentina/Buenos_Aires"));
+        assert_ok!(TimeZone().check("UTC"));
+        assert_ok!(TimeZone().check("US/Pacific"));
+    }
+    #[test]
+    fn time_zone_err() {
+        assert_err!(TimeZone().check("europe/paris"));
+        assert_err!(TimeZone().check("Europe"));
+        assert_err!(TimeZone().check(""));
+    }
+}
@@ -0,0 +1,54 @@
+use super::Rule;
+
+const CROCKFORD_BASE32: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an ULID
+///
+/// 26 characters of the Crockford's Base32 alphabet, in lowercase
+/// or uppercase, that don't exceed the maximum 128 bits value
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Id(#[rule(Ulid())] String);
+///
+/// assert!(Id(String::from("01ARZ3NDEKTSV4RRFFQ69G5FAV")).check_validity().is_ok());
+/// assert!(Id(String::from("01ARZ3NDEKTSV4RRFFQ69G5FAU")).check_validity().is_err());
+/// ```
+pub struct Ulid();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for Ulid {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref().as_bytes();
+        let valid = value.len() == 26
+            && value[0] <= b'7'
+            && value
+                .iter()
+                .all(|c| CROCKFORD_BASE32.contains(&c.to_ascii_uppercase()));
+        match valid {
+            true => Ok(()),
+            false => Err(String::from("The value is not an ULID")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Rule, Ulid};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn ulid_ok() {
+        assert_ok!(Ulid().check("01arz3ndektsv4rrffq69g5fav"));
+        assert_ok!(Ulid().check("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"));
+    }
+    #[test]
+    fn ulid_err() {
+        assert_err!(Ulid().check("01ARZ3NDEKTSV4RRFFQ69G5FA"));
+        assert_err!(Ulid().check("01ARZ3NDEKTSV4RRFFQ69G5FAI"));
+        assert_err!(Ulid().check("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"));
+    }
+}
@@ -0,0 +1,293 @@
+use super::char_class::{check_chars, in_table, BIDI_CONTROLS, ZERO_WIDTH_CHARS};
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not contain bidirectional formatting
+/// characters
+///
+/// The marks, embeddings, overrides and isolates such as the right-to-left
+/// override `U+202E` or the right-to-left mark `U+200F` change the display
+/// order of the text, which can make `"user\u{202E}gnp.exe"` look like
+/// `"userexe.png"`
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Username(#[rule(NoBidiOverride())] String);
+///
+/// assert!(Username(String::from("مستخدم")).check_validity().is_ok());
+/// assert!(Username(String::from("user\u{202E}gnp.exe")).check_validity().is_err());
+/// ```
+pub struct NoBidiOverride();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not contain zero width characters
+///
+/// The zero width space, non-joiner and joiner, the word joiner and the
+/// invisible operators, the Mongolian vowel separator and the byte order
+/// mark are rejected, so two strings that are displayed the same can't
+/// differ by an invisible character
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Username(#[rule(NoZeroWidth())] String);
+///
+/// assert!(Username(String::from("admin")).check_validity().is_ok());
+/// assert!(Username(String::from("ad\u{200B}min")).check_validity().is_err());
+/// ```
+pub struct NoZeroWidth();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not mix several scripts
+///
+/// The string is checked with the mixed-script detection of UTS 39:
+/// the common characters such as the digits and the punctuation go with
+/// any script, and the scripts written together such as the Han and the
+/// Katakana in Japanese count as one, so `"paypal"` written with a
+/// Cyrillic `"а"` is rejected but `"東京タワー"` is not
+///
+/// You need the `unicode` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Username(#[rule(NoMixedScript())] String);
+///
+/// assert!(Username(String::from("paypal_42")).check_validity().is_ok());
+/// assert!(Username(String::from("p\u{430}ypal")).check_validity().is_err());
+/// ```
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub struct NoMixedScript();
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to not be confusable with some protected names
+///
+/// Two strings are confusable when they have the same skeleton of UTS 39,
+/// ignoring the case, so `"R00T"` and `"rооt"` written with Cyrillic
+/// `"о"` are both confusable with `"root"`
+///
+/// You need the `unicode` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Username(#[rule(NotConfusableWith(&["admin", "root"]))] String);
+///
+/// assert!(Username(String::from("alice")).check_validity().is_ok());
+/// assert!(Username(String::from("r00t")).check_validity().is_err());
+/// ```
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub struct NotConfusableWith<'a>(pub &'a [&'a str]);
+
+/// Unicode normalization form of a string
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub enum NormalizationForm {
+    /// Canonical composition, such as `"é"` in one character
+    Nfc,
+    /// Canonical decomposition, such as `"e\u{301}"`
+    Nfd,
+    /// Compatibility composition, that also replaces the compatibility
+    /// characters such as `"ﬁ"` or `"①"` by `"fi"` and `"1"`
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be in a [`NormalizationForm`]
+///
+/// Use [`NormalizationForm::Nfkc`] for the identifiers, so a string
+/// can't be written in two ways that are displayed the same
+///
+/// You need the `unicode` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Username(#[rule(Normalized(NormalizationForm::Nfkc))] String);
+///
+/// assert!(Username(String::from("zoé")).check_validity().is_ok());
+/// assert!(Username(String::from("zoe\u{301}")).check_validity().is_err());
+/// assert!(Username(String::from("ﬁle")).check_validity().is_err());
+/// ```
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub struct Normalized(pub NormalizationForm);
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be in NFC, same as
+/// `Normalized(NormalizationForm::Nfc)`
+///
+/// You need the `unicode` feature to use it
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Name(#[rule(Nfc())] String);
+///
+/// assert!(Name(String::from("Zoé")).check_validity().is_ok());
+/// assert!(Name(String::from("Zoe\u{301}")).check_validity().is_err());
+/// ```
+#[cfg(feature = "unicode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+pub struct Nfc();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for NoBidiOverride {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(
+            value.as_ref(),
+            |c| !in_table(BIDI_CONTROLS, c),
+            "is a bidirectional formatting character",
+        )
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for NoZeroWidth {
+    fn check(&self, value: &T) -> Result<(), String> {
+        check_chars(
+            value.as_ref(),
+            |c| !in_table(ZERO_WIDTH_CHARS, c),
+            "is a zero width character",
+        )
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<T: AsRef<str> + ?Sized> Rule<T> for NoMixedScript {
+    fn check(&self, value: &T) -> Result<(), String> {
+        match unicode_security::MixedScript::is_single_script(value.as_ref()) {
+            true => Ok(()),
+            false => Err(String::from("The text mixes several scripts")),
+        }
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for NotConfusableWith<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value_skeleton = skeleton(value.as_ref());
+        match self.0.iter().find(|name| skeleton(name) == value_skeleton) {
+            Some(name) => Err(format!("The text is confusable with {:?}", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<T: AsRef<str> + ?Sized> Rule<T> for Normalized {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref();
+        let (is_normalized, name) = match self.0 {
+            NormalizationForm::Nfc => (unicode_normalization::is_nfc(value), "NFC"),
+            NormalizationForm::Nfd => (unicode_normalization::is_nfd(value), "NFD"),
+            NormalizationForm::Nfkc => (unicode_normalization::is_nfkc(value), "NFKC"),
+            NormalizationForm::Nfkd => (unicode_normalization::is_nfkd(value), "NFKD"),
+        };
+        match is_normalized {
+            true => Ok(()),
+            false => Err(format!("The text is not in {}", name)),
+        }
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<T: AsRef<str> + ?Sized> Rule<T> for Nfc {
+    fn check(&self, value: &T) -> Result<(), String> {
+        Normalized(NormalizationForm::Nfc).check(value)
+    }
+}
+
+/// Skeleton of UTS 39 lowercased, and computed again as the
+/// prototypes of some characters such as `'0'` are uppercase
+#[cfg(feature = "unicode")]
+fn skeleton(value: &str) -> String {
+    let skeleton = unicode_security::skeleton(value).collect::<String>();
+    unicode_security::skeleton(&skeleton.to_lowercase()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{NoBidiOverride, NoZeroWidth, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn no_bidi_override() {
+        assert_ok!(NoBidiOverride().check("שלום hello"));
+        assert_err!(NoBidiOverride().check("a\u{2067}b"));
+        assert_err!(NoBidiOverride().check("a\u{200E}b"));
+        assert_err!(NoBidiOverride().check("a\u{200F}b"));
+        assert_err!(NoBidiOverride().check("\u{61C}file"));
+        let res_error_message = NoBidiOverride()
+            .check("user\u{202E}gnp.exe")
+            .expect_err("Should be an Err");
+        assert_eq!(
+            res_error_message,
+            "The character '\\u{202e}' at position 4 is a bidirectional formatting character"
+        );
+    }
+    #[test]
+    fn no_zero_width() {
+        assert_ok!(NoZeroWidth().check("admin"));
+        assert_err!(NoZeroWidth().check("\u{FEFF}admin"));
+        assert_err!(NoZeroWidth().check("ad\u{200D}min"));
+        assert_err!(NoZeroWidth().check("ad\u{180E}min"));
+        assert_err!(NoZeroWidth().check("ad\u{2061}min"));
+        assert_err!(NoZeroWidth().check("ad\u{2064}min"));
+    }
+
+    #[cfg(feature = "unicode")]
+    mod unicode_tests {
+        use crate::rules::{
+            Nfc, NoMixedScript, NormalizationForm, Normalized, NotConfusableWith, Rule,
+        };
+        use claim::{assert_err, assert_ok};
+
+        #[test]
+        fn no_mixed_script() {
+            assert_ok!(NoMixedScript().check("Zoé-42"));
+            assert_ok!(NoMixedScript().check("東京タワー"));
+            assert_ok!(NoMixedScript().check("Москва"));
+            let res_error_message = NoMixedScript()
+                .check("p\u{430}ypal")
+                .expect_err("Should be an Err");
+            assert_eq!(res_error_message, "The text mixes several scripts");
+        }
+        #[test]
+        fn not_confusable_with() {
+            let rule = NotConfusableWith(&["admin", "paypal"]);
+            assert_ok!(rule.check("alice"));
+            assert_err!(rule.check("Admin"));
+            assert_err!(NotConfusableWith(&["root"]).check("R00T"));
+            assert_err!(rule.check("\u{430}dmin"));
+            let res_error_message = rule.check("paypa1").expect_err("Should be an Err");
+            assert_eq!(res_error_message, "The text is confusable with \"paypal\"");
+        }
+        #[test]
+        fn normalized() {
+            assert_ok!(Nfc().check("Zoé"));
+            assert_err!(Nfc().check("Zoe\u{301}"));
+            assert_ok!(Normalized(NormalizationForm::Nfd).check("Zoe\u{301}"));
+            assert_ok!(Nfc().check("ﬁle"));
+            let res_error_message = Normalized(NormalizationForm::Nfkc)
+                .check("ﬁle")
+                .expect_err("Should be an Err");
+            assert_eq!(res_error_message, "The text is not in NFKC");
+        }
+    }
+}
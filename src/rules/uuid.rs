@@ -0,0 +1,127 @@
+use super::Rule;
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an UUID in its canonical form
+/// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`, in lowercase or uppercase
+///
+/// The UUID can be restricted to some versions, that also requires
+/// the RFC 9562 variant, and the nil UUID can be rejected
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Id(#[rule(Uuid::new().versions(&[4, 7]))] String);
+///
+/// assert!(Id(String::from("9b2e4c1a-3f6d-4e8b-a1c2-0d9e8f7a6b5c")).check_validity().is_ok());
+/// assert!(Id(String::from("9b2e4c1a-3f6d-1e8b-a1c2-0d9e8f7a6b5c")).check_validity().is_err());
+/// assert!(Id(String::from("9b2e4c1a3f6d4e8ba1c20d9e8f7a6b5c")).check_validity().is_err());
+/// ```
+pub struct Uuid<'a> {
+    versions: Option<&'a [u8]>,
+    forbid_nil: bool,
+}
+
+impl<'a> Uuid<'a> {
+    /// Accept any UUID in its canonical form
+    pub const fn new() -> Self {
+        Uuid {
+            versions: None,
+            forbid_nil: false,
+        }
+    }
+
+    /// Only accept the UUIDs of one of these `versions`
+    pub const fn versions(self, versions: &'a [u8]) -> Self {
+        Uuid {
+            versions: Some(versions),
+            ..self
+        }
+    }
+
+    /// Reject the nil UUID `00000000-0000-0000-0000-000000000000`
+    pub const fn forbid_nil(self) -> Self {
+        Uuid {
+            forbid_nil: true,
+            ..self
+        }
+    }
+}
+
+impl<'a> Default for Uuid<'a> {
+    fn default() -> Self {
+        Uuid::new()
+    }
+}
+
+impl<'a, T: AsRef<str> + ?Sized> Rule<T> for Uuid<'a> {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let value = value.as_ref().as_bytes();
+        if !is_canonical_uuid(value) {
+            return Err(String::from("The value is not an UUID"));
+        }
+        if self.forbid_nil && value.iter().all(|c| matches!(c, b'0' | b'-')) {
+            return Err(String::from("The UUID must not be nil"));
+        }
+        if let Some(versions) = self.versions {
+            let version = hex_value(value[14]);
+            let variant = hex_value(value[19]);
+            if !versions.contains(&version) || variant & 0b1100 != 0b1000 {
+                return Err(String::from("The version of the UUID is not allowed"));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_canonical_uuid(value: &[u8]) -> bool {
+    value.len() == 36
+        && value.iter().enumerate().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => *c == b'-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+fn hex_value(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        _ => c.to_ascii_lowercase() - b'a' + 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{Rule, Uuid};
+    use claim::{assert_err, assert_ok};
+
+    const NIL: &str = "00000000-0000-0000-0000-000000000000";
+
+    #[test]
+    fn uuid_ok() {
+        assert_ok!(Uuid::new().check("9B2E4C1A-3F6D-4E8B-A1C2-0D9E8F7A6B5C"));
+        assert_ok!(Uuid::new().check(NIL));
+    }
+    #[test]
+    fn uuid_err() {
+        assert_err!(Uuid::new().check("9b2e4c1a-3f6d-4e8b-a1c2-0d9e8f7a6b5"));
+        assert_err!(Uuid::new().check("9b2e4c1a-3f6d-4e8b-a1c20-d9e8f7a6b5c"));
+        assert_err!(Uuid::new().check("{9b2e4c1a-3f6d-4e8b-a1c2-0d9e8f7a6b5c}"));
+        assert_err!(Uuid::new().check("9b2e4c1a-3f6d-4e8b-a1c2-0d9e8f7a6g5c"));
+    }
+    #[test]
+    fn uuid_nil_err() {
+        let res_error_message = Uuid::new()
+            .forbid_nil()
+            .check(NIL)
+            .expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The UUID must not be nil");
+    }
+    #[test]
+    fn uuid_version() {
+        let rule = Uuid::new().versions(&[7]);
+        assert_ok!(rule.check("01890a5d-ac96-774b-bcce-b302099a8057"));
+        assert_err!(rule.check("9b2e4c1a-3f6d-4e8b-a1c2-0d9e8f7a6b5c"));
+        assert_err!(rule.check("01890a5d-ac96-774b-7cce-b302099a8057"));
+    }
+}
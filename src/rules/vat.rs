@@ -0,0 +1,124 @@
+use super::Rule;
+
+/// Formats of the VAT numbers of each EU member state, after the
+/// country prefix: `#` is a digit, `A` a letter, `X` a digit or a
+/// letter, and other characters are literals
+const VAT_FORMATS: &[(&str, &[&str])] = &[
+    ("AT", &["U########"]),
+    ("BE", &["0#########", "1#########"]),
+    ("BG", &["#########", "##########"]),
+    ("CY", &["########A"]),
+    ("CZ", &["########", "#########", "##########"]),
+    ("DE", &["#########"]),
+    ("DK", &["########"]),
+    ("EE", &["#########"]),
+    ("EL", &["#########"]),
+    ("ES", &["X#######X"]),
+    ("FI", &["########"]),
+    ("FR", &["XX#########"]),
+    ("HR", &["###########"]),
+    ("HU", &["########"]),
+    ("IE", &["#######A", "#######AA", "#A#####A"]),
+    ("IT", &["###########"]),
+    ("LT", &["#########", "############"]),
+    ("LU", &["########"]),
+    ("LV", &["###########"]),
+    ("MT", &["########"]),
+    ("NL", &["#########B##"]),
+    ("PL", &["##########"]),
+    ("PT", &["#########"]),
+    (
+        "RO",
+        &[
+            "##",
+            "###",
+            "####",
+            "#####",
+            "######",
+            "#######",
+            "########",
+            "#########",
+            "##########",
+        ],
+    ),
+    ("SE", &["##########01"]),
+    ("SI", &["########"]),
+    ("SK", &["##########"]),
+    ("XI", &["#########", "############", "GD###", "HA###"]),
+];
+
+/// Rule to constraint any type that implements [`AsRef<str>`] such
+/// as [`String`] or `&str` to be an EU VAT number
+///
+/// The number starts with the prefix of its member state (`EL` for
+/// Greece and `XI` for Northern Ireland) and must match one of the
+/// formats of this state. Spaces are ignored. Only the format is
+/// checked, the check digits are specific to each state.
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator)]
+/// struct Company(#[rule(EuVat())] String);
+///
+/// assert!(Company(String::from("FR 40 303265045")).check_validity().is_ok());
+/// assert!(Company(String::from("FR 40 30326504")).check_validity().is_err());
+/// assert!(Company(String::from("US 40 303265045")).check_validity().is_err());
+/// ```
+pub struct EuVat();
+
+impl<T: AsRef<str> + ?Sized> Rule<T> for EuVat {
+    fn check(&self, value: &T) -> Result<(), String> {
+        let vat: String = value.as_ref().chars().filter(|c| *c != ' ').collect();
+        let formats = vat
+            .get(..2)
+            .and_then(|prefix| VAT_FORMATS.iter().find(|(country, _)| *country == prefix))
+            .map(|(_, formats)| *formats)
+            .ok_or_else(|| String::from("The VAT number country is unknown"))?;
+        match formats
+            .iter()
+            .any(|format| matches_format(&vat[2..], format))
+        {
+            true => Ok(()),
+            false => Err(String::from("The VAT number format is invalid")),
+        }
+    }
+}
+
+fn matches_format(value: &str, format: &str) -> bool {
+    value.len() == format.len()
+        && value.bytes().zip(format.bytes()).all(|(c, f)| match f {
+            b'#' => c.is_ascii_digit(),
+            b'A' => c.is_ascii_uppercase(),
+            b'X' => c.is_ascii_digit() || c.is_ascii_uppercase(),
+            _ => c == f,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{EuVat, Rule};
+    use claim::{assert_err, assert_ok};
+
+    #[test]
+    fn eu_vat_ok() {
+        assert_ok!(EuVat().check("DE136695976"));
+        assert_ok!(EuVat().check("ATU13585627"));
+        assert_ok!(EuVat().check("NL123456789B01"));
+        assert_ok!(EuVat().check("IE 6388047V"));
+    }
+    #[test]
+    fn eu_vat_country_err() {
+        let res_error_message = EuVat().check("GB123456789").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The VAT number country is unknown");
+        assert_err!(EuVat().check("D"));
+    }
+    #[test]
+    fn eu_vat_format_err() {
+        let res_error_message = EuVat().check("DE13669597").expect_err("Should be an Err");
+        assert_eq!(res_error_message, "The VAT number format is invalid");
+        assert_err!(EuVat().check("ATX13585627"));
+        assert_err!(EuVat().check("NL123456789C01"));
+    }
+}
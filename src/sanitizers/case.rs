@@ -0,0 +1,61 @@
+use super::Sanitizer;
+
+/// Sanitizer to convert a [`String`] to lowercase
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Sanitize)]
+/// struct Email(#[sanitizer(trim, lowercase)] String);
+///
+/// let mut email = Email(String::from("  Foo@Example.COM "));
+/// email.sanitize();
+/// assert_eq!(email.0, "foo@example.com");
+/// ```
+pub struct ToLowercase();
+
+/// Sanitizer to convert a [`String`] to uppercase
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Sanitize)]
+/// struct CountryCode(#[sanitizer(uppercase)] String);
+///
+/// let mut country = CountryCode(String::from("fr"));
+/// country.sanitize();
+/// assert_eq!(country.0, "FR");
+/// ```
+pub struct ToUppercase();
+
+impl Sanitizer<String> for ToLowercase {
+    fn sanitize(&self, value: &mut String) {
+        *value = value.to_lowercase();
+    }
+}
+
+impl Sanitizer<String> for ToUppercase {
+    fn sanitize(&self, value: &mut String) {
+        *value = value.to_uppercase();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sanitizers::{Sanitizer, ToLowercase, ToUppercase};
+
+    #[test]
+    fn lowercase() {
+        let mut value = String::from("Foo@Example.COM");
+        ToLowercase().sanitize(&mut value);
+        assert_eq!(value, "foo@example.com");
+    }
+    #[test]
+    fn uppercase() {
+        let mut value = String::from("Éa");
+        ToUppercase().sanitize(&mut value);
+        assert_eq!(value, "ÉA");
+    }
+}
@@ -0,0 +1,48 @@
+use super::Sanitizer;
+
+/// Sanitizer to replace each sequence of whitespaces
+/// of a [`String`] by a single space
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Sanitize)]
+/// struct FullName(#[sanitizer(trim, collapse_whitespace)] String);
+///
+/// let mut name = FullName(String::from(" John \t  Smith "));
+/// name.sanitize();
+/// assert_eq!(name.0, "John Smith");
+/// ```
+pub struct CollapseWhitespace();
+
+impl Sanitizer<String> for CollapseWhitespace {
+    fn sanitize(&self, value: &mut String) {
+        let mut previous_whitespace = false;
+        *value = value
+            .chars()
+            .filter_map(|c| {
+                let whitespace = c.is_whitespace();
+                let skip = whitespace && previous_whitespace;
+                previous_whitespace = whitespace;
+                match (skip, whitespace) {
+                    (true, _) => None,
+                    (false, true) => Some(' '),
+                    (false, false) => Some(c),
+                }
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sanitizers::{CollapseWhitespace, Sanitizer};
+
+    #[test]
+    fn collapse_whitespace() {
+        let mut value = String::from("John \t  Smith\n");
+        CollapseWhitespace().sanitize(&mut value);
+        assert_eq!(value, "John Smith ");
+    }
+}
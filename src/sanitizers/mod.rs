@@ -0,0 +1,44 @@
+mod case;
+mod collapse_whitespace;
+mod nested;
+mod phone;
+mod trim;
+
+pub use self::case::*;
+pub use self::collapse_whitespace::*;
+pub use self::nested::*;
+pub use self::phone::*;
+pub use self::trim::*;
+
+/// Define a transformation of a type
+///
+/// By implementing `Sanitizer` for a type you define how it
+/// will be used to normalize a value of type `T` before its checking.
+///
+/// The derive macro uses the `sanitizer` attribute to apply the sanitizers,
+/// it accepts `trim`, `lowercase`, `uppercase`, `collapse_whitespace`
+/// and `nested` keywords and any expression that implements `Sanitizer`.
+///
+/// # Example
+///
+/// ```
+/// use type_rules::prelude::*;
+///
+/// struct RemoveDashes();
+///
+/// impl Sanitizer<String> for RemoveDashes {
+///     fn sanitize(&self, value: &mut String) {
+///         value.retain(|c| c != '-');
+///     }
+/// }
+///
+/// #[derive(Sanitize)]
+/// struct PhoneNumber(#[sanitizer(trim, RemoveDashes())] String);
+///
+/// let mut phone = PhoneNumber(String::from(" 06-12-34-56-78 "));
+/// phone.sanitize();
+/// assert_eq!(phone.0, "0612345678");
+/// ```
+pub trait Sanitizer<T: ?Sized> {
+    fn sanitize(&self, value: &mut T);
+}
@@ -0,0 +1,50 @@
+use super::Sanitizer;
+use crate::Sanitize;
+
+/// Sanitizer to sanitize the inner type
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Sanitize)]
+/// struct Email(#[sanitizer(trim, lowercase)] String);
+///
+/// #[derive(Sanitize)]
+/// struct User {
+///     #[sanitizer(nested)]
+///     email: Email,
+/// };
+///
+/// let mut user = User { email: Email(String::from(" Foo@Example.COM")) };
+/// user.sanitize();
+/// assert_eq!(user.email.0, "foo@example.com");
+/// ```
+pub struct Nested();
+
+impl<T: Sanitize> Sanitizer<T> for Nested {
+    fn sanitize(&self, value: &mut T) {
+        value.sanitize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sanitizers::{Nested, Sanitizer, Trim};
+    use crate::Sanitize;
+
+    struct StringWrapper(String);
+
+    impl Sanitize for StringWrapper {
+        fn sanitize(&mut self) {
+            Trim().sanitize(&mut self.0)
+        }
+    }
+
+    #[test]
+    fn nested() {
+        let mut value = StringWrapper(String::from(" a "));
+        Nested().sanitize(&mut value);
+        assert_eq!(value.0, "a");
+    }
+}
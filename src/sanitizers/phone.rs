@@ -0,0 +1,116 @@
+use super::Sanitizer;
+use crate::rules::PhoneCountry;
+
+/// Sanitizer to turn a phone number of a [`String`] into the E.164
+/// format checked by the [`Phone`] rule
+///
+/// The spaces, dots, hyphens, slashes and parentheses are removed, as
+/// well as the `(0)` trunk prefix written after the calling code. The
+/// international `00` prefix is replaced by `+`. With a default country,
+/// the national numbers are prefixed by the calling code of this country
+/// instead of its trunk prefix. The other values are left as is.
+///
+/// [`Phone`]: crate::rules::Phone
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Validator, Sanitize)]
+/// struct Contact(
+///     #[sanitizer(NormalizePhone::new().default_country("FR"))]
+///     #[rule(Phone::new())]
+///     String
+/// );
+///
+/// let mut contact = Contact(String::from("+33 6 12 34 56 78"));
+/// contact.sanitize();
+/// assert_eq!(contact.0, "+33612345678");
+///
+/// let mut contact = Contact(String::from("06.12.34.56.78"));
+/// contact.sanitize();
+/// assert_eq!(contact.0, "+33612345678");
+/// ```
+pub struct NormalizePhone<'a> {
+    default_country: Option<&'a str>,
+}
+
+impl<'a> NormalizePhone<'a> {
+    /// Only normalize the international numbers
+    pub const fn new() -> Self {
+        NormalizePhone {
+            default_country: None,
+        }
+    }
+
+    /// Consider the numbers without international prefix as numbers
+    /// of this `country`, as an ISO 3166-1 alpha-2 code such as `"FR"`
+    pub const fn default_country(self, country: &'a str) -> Self {
+        NormalizePhone {
+            default_country: Some(country),
+        }
+    }
+}
+
+impl<'a> Default for NormalizePhone<'a> {
+    fn default() -> Self {
+        NormalizePhone::new()
+    }
+}
+
+impl<'a> Sanitizer<String> for NormalizePhone<'a> {
+    fn sanitize(&self, value: &mut String) {
+        let mut number = value.trim().replace("(0)", "");
+        number.retain(|c| !c.is_whitespace() && !".-/()".contains(c));
+        if !number.bytes().skip(1).all(|c| c.is_ascii_digit()) {
+            return;
+        }
+        let number = if number.starts_with('+') {
+            number
+        } else if let Some(international) = number.strip_prefix("00") {
+            format!("+{}", international)
+        } else {
+            let plan = match self.default_country.and_then(PhoneCountry::find) {
+                Some(plan) if number.starts_with(|c: char| c.is_ascii_digit()) => plan,
+                _ => return,
+            };
+            let national = number.strip_prefix(plan.trunk_prefix).unwrap_or(&number);
+            format!("+{}{}", plan.calling_code, national)
+        };
+        *value = number;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sanitizers::{NormalizePhone, Sanitizer};
+
+    #[test]
+    fn normalize_phone_international() {
+        let mut value = String::from(" +44 (0)20 7946-0958 ");
+        NormalizePhone::new().sanitize(&mut value);
+        assert_eq!(value, "+442079460958");
+        let mut value = String::from("0033 6 12 34 56 78");
+        NormalizePhone::new().sanitize(&mut value);
+        assert_eq!(value, "+33612345678");
+    }
+    #[test]
+    fn normalize_phone_national() {
+        let mut value = String::from("(415) 555-2671");
+        NormalizePhone::new()
+            .default_country("US")
+            .sanitize(&mut value);
+        assert_eq!(value, "+14155552671");
+        let mut value = String::from("06 12 34 56 78");
+        NormalizePhone::new().sanitize(&mut value);
+        assert_eq!(value, "06 12 34 56 78");
+    }
+    #[test]
+    fn normalize_phone_not_a_number() {
+        let mut value = String::from("call me");
+        NormalizePhone::new()
+            .default_country("FR")
+            .sanitize(&mut value);
+        assert_eq!(value, "call me");
+    }
+}
@@ -0,0 +1,43 @@
+use super::Sanitizer;
+
+/// Sanitizer to remove the leading and trailing whitespaces of a [`String`]
+///
+/// # Example
+/// ```
+/// use type_rules::prelude::*;
+///
+/// #[derive(Sanitize)]
+/// struct Username(#[sanitizer(trim)] String);
+///
+/// let mut username = Username(String::from("  example "));
+/// username.sanitize();
+/// assert_eq!(username.0, "example");
+/// ```
+pub struct Trim();
+
+impl Sanitizer<String> for Trim {
+    fn sanitize(&self, value: &mut String) {
+        let end = value.trim_end().len();
+        value.truncate(end);
+        let start = value.len() - value.trim_start().len();
+        value.drain(..start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sanitizers::{Sanitizer, Trim};
+
+    #[test]
+    fn trim() {
+        let mut value = String::from(" \t example \n");
+        Trim().sanitize(&mut value);
+        assert_eq!(value, "example");
+    }
+    #[test]
+    fn trim_only_whitespaces() {
+        let mut value = String::from("   ");
+        Trim().sanitize(&mut value);
+        assert_eq!(value, "");
+    }
+}
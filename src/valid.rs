@@ -15,7 +15,7 @@ use std::ops::Deref;
 /// struct NewUser {
 ///     #[rule(MinMaxLength(3, 50))]
 ///     username: String,
-///     #[rule(MinMaxLength(8, 100))]
+///     #[rule(MaxLength(100), PasswordStrength::new().min_length(8).forbid_common())]
 ///     password: String,
 /// }
 ///
@@ -0,0 +1,70 @@
+#![cfg(feature = "derive")]
+
+use claim::assert_ok;
+use type_rules::prelude::*;
+
+#[derive(Validator, Debug)]
+#[type_rules(builder)]
+struct Range {
+    #[rule(MaxRange(100))]
+    min: u32,
+    #[rule(MaxRange(100), MinRange(self.min))]
+    max: u32,
+    #[rule(Opt(MinMaxLength(1, 10)))]
+    label: Option<String>,
+}
+
+#[test]
+fn builder_ok() {
+    let mut builder = Range::builder();
+    builder.min(1).unwrap().max(10).unwrap();
+    let range = builder.build().unwrap();
+    assert_eq!((range.min, range.max), (1, 10));
+    assert_eq!(range.label, None);
+}
+#[test]
+fn builder_chained() -> Result<(), String> {
+    let range = Range::builder().min(1)?.max(10)?.label(None)?.build()?;
+    assert_eq!((range.min, range.max), (1, 10));
+    Ok(())
+}
+#[test]
+fn builder_setter_err() {
+    let mut builder = Range::builder();
+    assert!(builder.min(1).is_ok());
+    let res_error_message = builder.max(101).err();
+    assert_eq!(res_error_message.as_deref(), Some("Value is too high"));
+    assert!(builder.label(Some(String::new())).is_err());
+    assert!(builder.max(10).is_ok());
+    assert_ok!(builder.build());
+}
+#[test]
+fn builder_missing_field() {
+    let mut builder = Range::builder();
+    builder.min(1).unwrap();
+    let res_error_message = builder.build().expect_err("Should be an Err");
+    assert_eq!(res_error_message, "Missing field `max`");
+    builder.max(10).unwrap();
+    assert_ok!(builder.build());
+}
+#[test]
+fn builder_self_rules_in_build() {
+    let mut builder = Range::builder();
+    assert!(builder.min(10).is_ok());
+    assert!(builder.max(5).is_ok());
+    let res_error_message = builder.build().expect_err("Should be an Err");
+    assert_eq!(res_error_message, "Value is too low");
+}
+#[test]
+fn builder_option_field() {
+    let mut builder = Range::builder();
+    builder
+        .min(1)
+        .unwrap()
+        .max(10)
+        .unwrap()
+        .label(Some(String::from("ok")))
+        .unwrap();
+    let range = builder.build().unwrap();
+    assert_eq!(range.label.as_deref(), Some("ok"));
+}
@@ -0,0 +1,65 @@
+#![cfg(all(feature = "derive", feature = "regex"))]
+
+use claim::{assert_err, assert_ok};
+use type_rules::prelude::*;
+
+mod literal {
+    use type_rules::Rule;
+
+    /// Rule that compares the value to its pattern as is
+    pub struct RegEx(pub &'static str);
+
+    impl Rule<String> for RegEx {
+        fn check(&self, value: &String) -> Result<(), String> {
+            match value == self.0 {
+                true => Ok(()),
+                false => Err(String::from("Value is not the literal")),
+            }
+        }
+    }
+}
+
+mod remove {
+    use type_rules::Sanitizer;
+
+    /// Sanitizer that removes its pattern from the value
+    pub struct RegEx(pub &'static str);
+
+    impl Sanitizer<String> for RegEx {
+        fn sanitize(&self, value: &mut String) {
+            *value = value.replace(self.0, "");
+        }
+    }
+}
+
+#[derive(Validator)]
+struct Digits(#[rule(type_rules::rules::RegEx(r"^\d+$"))] String);
+
+#[derive(Validator)]
+struct Literal(#[rule(literal::RegEx("a+"))] String);
+
+#[test]
+fn regex_rule_path() {
+    assert_ok!(Digits(String::from("42")).check_validity());
+    assert_err!(Digits(String::from("4a")).check_validity());
+}
+#[test]
+fn regex_other_path_not_rewritten() {
+    assert_ok!(Literal(String::from("a+")).check_validity());
+    assert_err!(Literal(String::from("aaa")).check_validity());
+}
+
+mod sanitizer {
+    use super::remove::RegEx;
+    use type_rules::prelude::*;
+
+    #[derive(Sanitize)]
+    struct Name(#[sanitizer(RegEx("-"))] String);
+
+    #[test]
+    fn regex_sanitizer_not_rewritten() {
+        let mut name = Name(String::from("a-b-c"));
+        name.sanitize();
+        assert_eq!(name.0, "abc");
+    }
+}
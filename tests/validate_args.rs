@@ -0,0 +1,63 @@
+#![cfg(feature = "derive")]
+
+use claim::{assert_err, assert_ok};
+use type_rules::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct WordsError(String);
+
+impl From<String> for WordsError {
+    fn from(message: String) -> Self {
+        WordsError(message)
+    }
+}
+
+#[validate_args]
+#[ensure(MinRange(1))]
+fn count_words(#[rule(MinLength(1))] text: &str) -> Result<usize, WordsError> {
+    Ok(text.split_whitespace().count())
+}
+
+#[validate_args]
+#[ensure(MaxRange(100))]
+fn percent(#[rule(MaxRange(1000))] per_mille: u32) -> u32 {
+    per_mille / 10 + 1
+}
+
+#[test]
+fn validate_args_ok() {
+    assert_ok!(count_words("Hello world"));
+    assert_eq!(percent(500), 51);
+}
+#[test]
+fn validate_args_argument_err() {
+    assert_eq!(
+        count_words(""),
+        Err(WordsError(String::from(
+            "Invalid argument `text`: Value is too short"
+        )))
+    );
+}
+#[test]
+fn validate_args_ensure_err() {
+    let res = count_words(" ");
+    assert_err!(&res);
+    assert_eq!(
+        res,
+        Err(WordsError(String::from(
+            "Invalid return value: Value is too low"
+        )))
+    );
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Invalid argument `per_mille`: Value is too high")]
+fn validate_args_argument_panic() {
+    percent(1001);
+}
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Invalid return value: Value is too high")]
+fn validate_args_ensure_panic() {
+    percent(1000);
+}